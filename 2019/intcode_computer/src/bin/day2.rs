@@ -0,0 +1,35 @@
+use intcode::{Machine, State};
+
+const PUZZLE_INPUT: &str = include_str!("../../../2/input.txt");
+
+/// Run the program with the given noun and verb, returning address 0
+fn run(program: &Machine, noun: i64, verb: i64) -> i64 {
+    let mut machine = program.clone();
+    machine.write(1, noun);
+    machine.write(2, verb);
+    assert_eq!(machine.process(), Ok(State::Term));
+    machine.read(0)
+}
+
+fn part_one(program: &Machine) -> i64 {
+    // Set the 1202 condition
+    run(program, 12, 2)
+}
+
+fn part_two(program: &Machine) -> i64 {
+    for noun in 0..99 {
+        for verb in 0..99 {
+            if run(program, noun, verb) == 19690720 {
+                return 100 * noun + verb;
+            }
+        }
+    }
+
+    panic!("Unable to solve the puzzle :(");
+}
+
+fn main() {
+    let program: Machine = PUZZLE_INPUT.parse().unwrap();
+    println!("Day 2, part 1: {}", part_one(&program));
+    println!("Day 2, part 2: {}", part_two(&program));
+}
@@ -0,0 +1,18 @@
+use intcode::{Machine, State};
+
+const PUZZLE_INPUT: &str = include_str!("../../../5/input.txt");
+
+/// Run the diagnostics for a system, returning the final output
+fn diagnostic_code(program: &Machine, system_id: i64) -> i64 {
+    let mut machine = program.clone();
+    machine.push_input(system_id);
+    while machine.process().unwrap() != State::Term {}
+
+    *machine.output().last().unwrap()
+}
+
+fn main() {
+    let program: Machine = PUZZLE_INPUT.parse().unwrap();
+    println!("Day 5, part 1: {}", diagnostic_code(&program, 1));
+    println!("Day 5, part 2: {}", diagnostic_code(&program, 5));
+}
@@ -0,0 +1,156 @@
+use intcode::{parse_program, Machine, State};
+
+const PUZZLE_INPUT: &str = include_str!("../../../7/input.txt");
+
+fn main() {
+    let program = parse_program(PUZZLE_INPUT).unwrap();
+    println!("Day 7, part 1: {}", max_phase_sequence(&program));
+    println!("Day 7, part 2: {}", max_feedback_loop(&program));
+}
+
+fn phase_sequence(program: &[i64], phases: &Vec<i64>) -> i64 {
+    assert_eq!(phases.len(), 5);
+
+    let mut input: i64 = 0;
+    for phase in phases {
+        let mut amp = Machine::new(program);
+        amp.push_input(*phase);
+        amp.push_input(input);
+        assert_eq!(amp.process(), Ok(State::OutputReady));
+        input = amp.output()[0];
+    }
+
+    input
+}
+
+fn max_phase_sequence(program: &[i64]) -> i64 {
+    let mut phases = vec![0, 1, 2, 3, 4];
+    let mut heap = permutohedron::Heap::new(&mut phases);
+    let mut biggest = 0;
+    while let Some(perm) = heap.next_permutation() {
+        let try_out = phase_sequence(program, perm);
+        if try_out > biggest {
+            biggest = try_out;
+        }
+    }
+
+    biggest
+}
+
+fn feedback_loop(program: &[i64], phases: &[i64]) -> i64 {
+    assert_eq!(phases.len(), 5);
+    let mut amps: Vec<Machine> = Vec::with_capacity(5);
+    for phase in phases {
+        let mut a = Machine::new(program);
+        a.push_input(*phase);
+        amps.push(a);
+    }
+    // Seed the first amplifier
+    amps[0].push_input(0);
+    let mut last_signal = 0;
+
+    let mut term_count = 0;
+    loop {
+        if term_count >= 5 {
+            // all amplifiers have finished
+            break;
+        }
+        for idx in 0..5 {
+            if term_count >= 5 {
+                break;
+            }
+            match amps[idx].process().unwrap() {
+                State::InputWaiting => {
+                    // Can't do anything if it's waiting for input, so
+                    // we pass and hope that the next loop will come
+                    // with an input
+                    break;
+                }
+                State::OutputReady => {
+                    // Output is ready, so we take it and push it into
+                    // the next amplifier's input stage
+                    let val = amps[idx].pop_output().unwrap();
+                    if idx == 4 {
+                        last_signal = val;
+                    }
+                    amps[(idx + 1) % 5].push_input(val);
+                }
+                State::Term => {
+                    term_count += 1;
+                }
+                State::Running => panic!(),
+            }
+        }
+    }
+
+    // The final output is the last signal the last amplifier sent back round
+    // to the first
+    last_signal
+}
+
+fn max_feedback_loop(program: &[i64]) -> i64 {
+    let mut phases = vec![5, 6, 7, 8, 9];
+    let mut heap = permutohedron::Heap::new(&mut phases);
+    let mut biggest = 0;
+    while let Some(perm) = heap.next_permutation() {
+        let try_out = feedback_loop(program, perm);
+        if try_out > biggest {
+            biggest = try_out;
+        }
+    }
+
+    biggest
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn day_7_test_1() {
+        let program = vec![
+            3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+        ];
+
+        assert_eq!(phase_sequence(&program, &vec![4, 3, 2, 1, 0]), 43210);
+    }
+
+    #[test]
+    fn day_7_test_2() {
+        let program = vec![
+            3, 23, 3, 24, 1002, 24, 10, 24, 1002, 23, -1, 23, 101, 5, 23, 23,
+            1, 24, 23, 23, 4, 23, 99, 0, 0,
+        ];
+
+        assert_eq!(max_phase_sequence(&program), 54321);
+    }
+
+    #[test]
+    fn day_7_test_3() {
+        let program = vec![
+            3, 31, 3, 32, 1002, 32, 10, 32, 1001, 31, -2, 31, 1007, 31, 0, 33,
+            1002, 33, 7, 33, 1, 33, 31, 31, 1, 32, 31, 31, 4, 31, 99, 0, 0, 0,
+        ];
+        assert_eq!(max_phase_sequence(&program), 65210);
+    }
+
+    #[test]
+    fn day_7_test_4() {
+        let program = vec![
+            3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4,
+            27, 1001, 28, -1, 28, 1005, 28, 6, 99, 0, 0, 5,
+        ];
+        assert_eq!(feedback_loop(&program, &[9, 8, 7, 6, 5]), 139629729);
+    }
+
+    #[test]
+    fn day_7_test_5() {
+        let program = vec![
+            3, 52, 1001, 52, -5, 52, 3, 53, 1, 52, 56, 54, 1007, 54, 5, 55,
+            1005, 55, 26, 1001, 54, -5, 54, 1105, 1, 12, 1, 53, 54, 53, 1008,
+            54, 0, 55, 1001, 55, 1, 55, 2, 53, 55, 53, 4, 53, 1001, 56, -1, 56,
+            1005, 56, 6, 99, 0, 0, 0, 0, 10,
+        ];
+        assert_eq!(max_feedback_loop(&program), 18216);
+    }
+}
@@ -0,0 +1,17 @@
+use intcode::{Machine, State};
+
+const PUZZLE_INPUT: &str = include_str!("../../../9/input.txt");
+
+fn part_one(program: &Machine) -> i64 {
+    let mut machine = program.clone();
+    machine.push_input(1);
+    while machine.process().unwrap() == State::OutputReady {}
+    assert_eq!(machine.state(), State::Term);
+
+    machine.output()[0]
+}
+
+fn main() {
+    let program: Machine = PUZZLE_INPUT.parse().unwrap();
+    println!("Day 9, part 1: {}", part_one(&program));
+}
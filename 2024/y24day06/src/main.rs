@@ -2,7 +2,7 @@
 
 use aoc_grid::{Direction, Grid};
 use color_eyre::Result;
-use std::str::FromStr;
+use std::{convert::Infallible, str::FromStr};
 
 #[derive(Copy, Clone, Debug)]
 enum CellKind {
@@ -45,19 +45,8 @@ impl FromStr for DataType {
     type Err = color_eyre::Report;
 
     fn from_str(inp: &str) -> std::result::Result<Self, Self::Err> {
-        let mut data = Vec::with_capacity(inp.len());
-        let mut width = None;
-        for line in inp.lines() {
-            if let Some(width) = width {
-                assert_eq!(width, line.len());
-            } else {
-                width = Some(line.len());
-            }
-
-            data.extend(line.chars().map(Cell::from));
-        }
-
-        let grid = Grid::new(data, width.unwrap().try_into().unwrap());
+        let grid =
+            Grid::parse(inp, |chr| Ok::<_, Infallible>(Cell::from(chr)))?;
         Ok(Self { grid })
     }
 }
@@ -1,7 +1,5 @@
 use aoc_grid::{Coord, Grid};
 use color_eyre::Result;
-use petgraph::{graphmap::GraphMap, Undirected};
-use rustc_hash::FxBuildHasher;
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
@@ -55,82 +53,44 @@ impl<const DIM: i64, const LINES_TO_READ: usize> FromStr
     }
 }
 
-fn make_grid_into_graph(
-    grid: &Grid<CellType>,
-) -> GraphMap<Coord, (), Undirected, FxBuildHasher> {
-    let mut graph = GraphMap::<_, _, Undirected, FxBuildHasher>::default();
-
-    for (coord, _) in grid
-        .iter()
-        .filter(|&(_, &celltype)| celltype == CellType::Safe)
-    {
-        // Add routes to non-danger neighbours
-        for (neigh, _) in grid
-            .iter_cardinal_neighbours(coord)
-            .filter(|&(_, &celltype)| celltype == CellType::Safe)
-        {
-            graph.add_edge(coord, neigh, ());
-        }
-    }
-
-    graph
+fn shortest_route<const DIM: i64>(grid: &Grid<CellType>) -> Option<u64> {
+    grid.bfs((0, 0), (DIM, DIM), |(_, &celltype)| {
+        celltype == CellType::Safe
+    })
+    .map(|route| route.distance)
 }
 
 fn part_one<const DIM: i64, const LINES_TO_READ: usize>(
     inp: &DataType<{ DIM }, { LINES_TO_READ }>,
 ) -> u64 {
-    let graph = make_grid_into_graph(&inp.grid);
-    let origin = Coord::from((0, 0));
-    let destination = Coord::from((DIM, DIM));
-
-    let dijk = petgraph::algo::dijkstra::dijkstra(
-        &graph,
-        origin,
-        Some(destination),
-        |_| 1,
-    );
-
-    *dijk.get(&destination).unwrap()
+    shortest_route::<DIM>(&inp.grid).unwrap()
 }
 
 fn part_two<const DIM: i64, const LINES_TO_READ: usize>(
     inp: &DataType<{ DIM }, { LINES_TO_READ }>,
 ) -> String {
-    let mut graph = make_grid_into_graph(&inp.grid);
-    let origin = Coord::from((0, 0));
-    let destination = Coord::from((DIM, DIM));
-
-    let mut jobs = Vec::with_capacity(inp.spare_bytes.len());
-    for &next_byte in &inp.spare_bytes {
-        // Delete related edges from the graph
-        graph.remove_node(next_byte);
-
-        let graph = graph.clone();
-        let handle = std::thread::spawn(move || {
-            let dijk = petgraph::algo::dijkstra::dijkstra(
-                &graph,
-                origin,
-                Some(destination),
-                |_| 1,
-            );
-
-            if dijk.contains_key(&destination) {
-                None
-            } else {
-                Some(format!("{},{}", next_byte.x, next_byte.y))
-            }
-        });
-        jobs.push(handle);
-    }
-
-    for handle in jobs {
-        let result = handle.join().unwrap();
-        if let Some(result) = result {
-            return result;
+    let blocked_after = |last: usize| {
+        let mut grid = inp.grid.clone();
+        for &byte in &inp.spare_bytes[..=last] {
+            grid.set(byte, CellType::Danger);
+        }
+        shortest_route::<DIM>(&grid).is_none()
+    };
+
+    // Once the exit is cut off it stays cut off, so binary search for the
+    // first byte that blocks it
+    let (mut lo, mut hi) = (0, inp.spare_bytes.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if blocked_after(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
         }
     }
 
-    panic!();
+    let byte = inp.spare_bytes[lo];
+    format!("{},{}", byte.x, byte.y)
 }
 
 fn main() -> Result<()> {
@@ -0,0 +1,177 @@
+use crate::{coord::Coord, Direction8, Grid, Neighbourhood, Space};
+use std::{collections::HashMap, hash::Hash};
+
+#[cfg(test)]
+#[path = "automaton_test.rs"]
+mod automaton_test;
+
+/// How an automaton over a `Grid` finds the cells that influence each cell
+#[derive(Clone, Debug)]
+pub enum Neighbours<T> {
+    /// Fixed offsets from each cell
+    Offsets(Neighbourhood),
+    /// The first cell in each of the eight directions for which the
+    /// predicate holds, e.g. the first visible seat
+    LineOfSight(fn(&T) -> bool),
+}
+
+/// A space which can be evolved by an `Automaton`
+pub trait CellularSpace: Space + Clone {
+    /// Choice of which cells count as neighbours
+    type Neighbours: Clone;
+
+    /// Replace the contents of `buf` with the neighbours of `at`
+    fn collect_neighbours<'a>(
+        &'a self,
+        at: Self::Coord,
+        kind: &Self::Neighbours,
+        buf: &mut Vec<&'a Self::Cell>,
+    );
+
+    /// Overwrite `next` with the result of applying `update` to every cell
+    /// which might change, returning the number of cells that did change
+    fn step_into<F>(&self, next: &mut Self, update: F) -> usize
+    where
+        F: FnMut(Self::Coord, &Self::Cell) -> Self::Cell;
+}
+
+impl<T: Clone + PartialEq> CellularSpace for Grid<T> {
+    type Neighbours = Neighbours<T>;
+
+    fn collect_neighbours<'a>(
+        &'a self,
+        at: Coord,
+        kind: &Neighbours<T>,
+        buf: &mut Vec<&'a T>,
+    ) {
+        buf.clear();
+        match kind {
+            Neighbours::Offsets(kind) => {
+                buf.extend(
+                    kind.oriented_offsets(self.orientation())
+                        .filter_map(|offset| self.get(at + offset)),
+                );
+            }
+            Neighbours::LineOfSight(visible) => {
+                buf.extend(Direction8::iter().filter_map(|dir| {
+                    self.ray(at, dir)
+                        .find(|(_, cell)| visible(cell))
+                        .map(|(_, val)| val)
+                }));
+            }
+        }
+    }
+
+    fn step_into<F>(&self, next: &mut Self, mut update: F) -> usize
+    where
+        F: FnMut(Coord, &T) -> T,
+    {
+        let mut changed = 0;
+        for ((at, cell), (_, slot)) in self.iter().zip(next.iter_mut()) {
+            let new = update(at, cell);
+            if new != *cell {
+                changed += 1;
+            }
+            *slot = new;
+        }
+        changed
+    }
+}
+
+/// How a run of an automaton came to an end
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Settled {
+    /// Stepping no longer changes any cells, first seen at `generation`
+    FixedPoint { generation: usize },
+    /// The state at `start` recurs every `length` generations
+    Cycle { start: usize, length: usize },
+}
+
+/// Double-buffered cellular automaton over a `Grid` or other cellular space
+#[derive(Clone, Debug)]
+pub struct Automaton<S: CellularSpace> {
+    current: S,
+    next: S,
+    neighbours: S::Neighbours,
+    generation: usize,
+}
+
+impl<S: CellularSpace> Automaton<S> {
+    pub fn new(grid: S, neighbours: S::Neighbours) -> Self {
+        Self {
+            next: grid.clone(),
+            current: grid,
+            neighbours,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &S {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> S {
+        self.current
+    }
+
+    /// Number of steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Apply `rule` to every cell at once, returning how many cells changed.
+    /// The rule is given the current cell and the cells neighbouring it.
+    pub fn step<R>(&mut self, mut rule: R) -> usize
+    where
+        R: FnMut(&S::Cell, &[&S::Cell]) -> S::Cell,
+    {
+        let current = &self.current;
+        let kind = &self.neighbours;
+        let mut buf = Vec::new();
+        let changed = current.step_into(&mut self.next, |at, cell| {
+            current.collect_neighbours(at, kind, &mut buf);
+            rule(cell, &buf)
+        });
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Step until nothing changes. Never returns if the automaton cycles.
+    pub fn run_until_stable<R>(&mut self, mut rule: R) -> Settled
+    where
+        R: FnMut(&S::Cell, &[&S::Cell]) -> S::Cell,
+    {
+        while self.step(&mut rule) > 0 {}
+        Settled::FixedPoint {
+            generation: self.generation - 1,
+        }
+    }
+
+    /// Step until a previously seen state recurs. A fixed point is reported
+    /// as such rather than as a cycle of length 1.
+    pub fn run_until_repeat<R>(&mut self, mut rule: R) -> Settled
+    where
+        R: FnMut(&S::Cell, &[&S::Cell]) -> S::Cell,
+        S: Hash + Eq,
+    {
+        let mut seen = HashMap::new();
+        seen.insert(self.current.clone(), self.generation);
+        loop {
+            if self.step(&mut rule) == 0 {
+                return Settled::FixedPoint {
+                    generation: self.generation - 1,
+                };
+            }
+            if let Some(start) =
+                seen.insert(self.current.clone(), self.generation)
+            {
+                return Settled::Cycle {
+                    start,
+                    length: self.generation - start,
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::*;
+use crate::Orientation;
+
+const SEATS: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+fn seating_rule(crowded: usize) -> impl FnMut(&char, &[&char]) -> char {
+    move |cell, neighbours| {
+        let occupied = neighbours.iter().filter(|seat| ***seat == '#').count();
+        match cell {
+            'L' if occupied == 0 => '#',
+            '#' if occupied >= crowded => 'L',
+            other => *other,
+        }
+    }
+}
+
+fn occupied(grid: &Grid<char>) -> usize {
+    grid.iter().filter(|(_, cell)| **cell == '#').count()
+}
+
+#[test]
+fn seating_adjacent() {
+    let grid: Grid<char> = SEATS.parse().unwrap();
+    let mut automaton =
+        Automaton::new(grid, Neighbours::Offsets(Neighbourhood::Moore));
+
+    assert!(format!("{automaton:?}").starts_with("Automaton"));
+    assert_eq!(automaton.step(seating_rule(4)), 71);
+    assert_eq!(automaton.generation(), 1);
+    assert_eq!(occupied(automaton.grid()), 71);
+
+    let settled = automaton.run_until_stable(seating_rule(4));
+    assert_eq!(settled, Settled::FixedPoint { generation: 5 });
+    assert_eq!(occupied(automaton.grid()), 37);
+}
+
+#[test]
+fn seating_line_of_sight() {
+    let grid: Grid<char> = SEATS.parse().unwrap();
+    let mut automaton =
+        Automaton::new(grid, Neighbours::LineOfSight(|cell| *cell != '.'));
+    let settled = automaton.run_until_repeat(seating_rule(5));
+    assert!(matches!(settled, Settled::FixedPoint { .. }));
+    assert_eq!(occupied(automaton.grid()), 26);
+}
+
+#[test]
+fn blinker_cycles() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        false, false, false,
+        true, true, true,
+        false, false, false,
+    ], 3);
+    let life = |cell: &bool, neighbours: &[&bool]| {
+        let alive = neighbours.iter().filter(|cell| ***cell).count();
+        matches!((cell, alive), (true, 2 | 3) | (false, 3))
+    };
+
+    let mut automaton =
+        Automaton::new(grid.clone(), Neighbours::Offsets(Neighbourhood::Moore));
+    assert_eq!(automaton.step(life), 4);
+    assert_eq!(automaton.grid(), &grid.transpose());
+
+    let mut automaton =
+        Automaton::new(grid, Neighbours::Offsets(Neighbourhood::Moore));
+    assert_eq!(
+        automaton.run_until_repeat(life),
+        Settled::Cycle {
+            start: 0,
+            length: 2
+        }
+    );
+}
+
+#[test]
+fn custom_offsets_use_grid_coordinates() {
+    // Every cell takes the value of the cell above it, so the picture
+    // moves down a row. Which way is up depends on the orientation.
+    let grid: Grid<char> = "#..\n.#.\n..#\n".parse().unwrap();
+    let fall = |_: &char, neighbours: &[&char]| {
+        neighbours.first().map_or('.', |cell| **cell)
+    };
+    for (orientation, up) in [(Orientation::YUp, 1), (Orientation::YDown, -1)] {
+        let grid = grid.clone().with_orientation(orientation);
+        let above = Neighbourhood::Custom(vec![(0, up).into()]);
+        let mut automaton = Automaton::new(grid, Neighbours::Offsets(above));
+        automaton.step(fall);
+        assert_eq!(automaton.grid().to_string(), "...\n#..\n.#.\n");
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{coord::Coord, Direction, Direction8, Grid, Orientation, Space};
+use std::{
+    fmt::Display,
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not,
+    },
+};
+
+#[cfg(test)]
+#[path = "bitgrid_test.rs"]
+mod bitgrid_test;
+
+const WORD_BITS: i64 = 64;
+
+/// A grid of booleans packed into machine words. Rows are stored top first
+/// as in `Grid`, each starting on a fresh word, and the unused bits at the
+/// end of a row are always clear so that equality and hashing can work on
+/// whole words.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: i64,
+    height: i64,
+    orientation: Orientation,
+}
+
+impl BitGrid {
+    /// A grid with every cell clear
+    pub fn new(width: i64, height: i64) -> Self {
+        assert!(width > 0 && height >= 0);
+        let row_words =
+            usize::try_from((width + WORD_BITS - 1) / WORD_BITS).unwrap();
+        let len = row_words
+            .checked_mul(usize::try_from(height).unwrap())
+            .unwrap();
+        Self {
+            words: vec![0; len],
+            width,
+            height,
+            orientation: Orientation::default(),
+        }
+    }
+
+    /// Set the cells of `grid` for which `is_set` holds
+    pub fn from_grid<T, F>(grid: &Grid<T>, mut is_set: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut bits = Self::new(grid.width(), grid.height())
+            .with_orientation(grid.orientation());
+        for (at, cell) in grid.iter() {
+            if is_set(cell) {
+                bits.set(at, true);
+            }
+        }
+        bits
+    }
+
+    /// See `Grid::with_orientation`
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    fn row_words(&self) -> usize {
+        usize::try_from((self.width + WORD_BITS - 1) / WORD_BITS).unwrap()
+    }
+
+    /// Bits of the final word in each row that hold cells
+    fn last_word_mask(&self) -> u64 {
+        match self.width % WORD_BITS {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    /// Storage row for a y coordinate and vice versa
+    fn row_index(&self, y: i64) -> i64 {
+        match self.orientation {
+            Orientation::YUp => self.height - 1 - y,
+            Orientation::YDown => y,
+        }
+    }
+
+    fn locate(&self, at: Coord) -> Option<(usize, u64)> {
+        if at.x < 0 || at.y < 0 || at.x >= self.width || at.y >= self.height {
+            return None;
+        }
+        let row = usize::try_from(self.row_index(at.y)).unwrap();
+        let word =
+            row * self.row_words() + usize::try_from(at.x / WORD_BITS).unwrap();
+        Some((word, 1 << (at.x % WORD_BITS)))
+    }
+
+    pub fn get(&self, at: impl Into<Coord>) -> Option<&bool> {
+        self.locate(at.into()).map(|(word, mask)| {
+            if self.words[word] & mask != 0 {
+                &true
+            } else {
+                &false
+            }
+        })
+    }
+
+    pub fn set(&mut self, at: impl Into<Coord>, value: bool) {
+        let at = at.into();
+        let Some((word, mask)) = self.locate(at) else {
+            panic!(
+                "Coordinate {} out of bounds in a grid of dimension ({}, {})",
+                at, self.width, self.height
+            );
+        };
+        if value {
+            self.words[word] |= mask;
+        } else {
+            self.words[word] &= !mask;
+        }
+    }
+
+    /// Number of set cells
+    pub fn count_ones(&self) -> u64 {
+        self.words
+            .iter()
+            .map(|word| u64::from(word.count_ones()))
+            .sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &bool)> {
+        (0..self.height).flat_map(move |row| {
+            let y = self.row_index(row);
+            (0..self.width).map(move |x| {
+                let at = Coord::from((x, y));
+                (at, self.get(at).unwrap())
+            })
+        })
+    }
+
+    /// Coordinates of the set cells, in the same order as `iter`
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        let row_words = self.row_words();
+        self.words.iter().enumerate().flat_map(move |(idx, &word)| {
+            let row = i64::try_from(idx / row_words).unwrap();
+            let base = i64::try_from(idx % row_words).unwrap() * WORD_BITS;
+            let y = self.row_index(row);
+            std::iter::successors((word != 0).then_some(word), |word| {
+                let rest = word & (word - 1);
+                (rest != 0).then_some(rest)
+            })
+            .map(move |word| {
+                let x = base + i64::from(word.trailing_zeros());
+                Coord::from((x, y))
+            })
+        })
+    }
+
+    pub fn get_neighbour(
+        &self,
+        at: impl Into<Coord>,
+        direction: Direction,
+    ) -> Option<(Coord, &bool)> {
+        let at = at.into() + self.orientation.vector(direction);
+        self.get(at).map(|val| (at, val))
+    }
+
+    pub fn iter_cardinal_neighbours(
+        &self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &bool)> {
+        let at = at.into();
+        Direction::iter().filter_map(move |dir| self.get_neighbour(at, dir))
+    }
+
+    pub fn iter_all_neighbours(
+        &self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &bool)> {
+        let at = at.into();
+        Direction8::iter().filter_map(move |dir| {
+            let at = at + self.orientation.vector(dir);
+            self.get(at).map(|val| (at, val))
+        })
+    }
+
+    /// Move every cell one step in `direction`, as seen on the page. Cells
+    /// pushed over the edge are lost and the vacated edge is cleared.
+    #[must_use]
+    pub fn shift(&self, direction: impl Into<Direction8>) -> Self {
+        let direction = direction.into();
+        let vector = Orientation::YUp.vector(direction);
+        let mut shifted = self.clone();
+        let row_words = self.row_words();
+
+        // Storage rows run top to bottom, so moving up is towards row 0
+        let len = shifted.words.len();
+        if row_words < len {
+            match vector.y {
+                1 => {
+                    shifted.words.copy_within(row_words.., 0);
+                    shifted.words[len - row_words..].fill(0);
+                }
+                -1 => {
+                    shifted.words.copy_within(..len - row_words, row_words);
+                    shifted.words[..row_words].fill(0);
+                }
+                _ => {}
+            }
+        } else if vector.y != 0 {
+            shifted.words.fill(0);
+        }
+
+        let mask = self.last_word_mask();
+        for row in shifted.words.chunks_mut(row_words) {
+            match vector.x {
+                1 => {
+                    for idx in (1..row.len()).rev() {
+                        row[idx] = (row[idx] << 1) | (row[idx - 1] >> 63);
+                    }
+                    row[0] <<= 1;
+                    row[row.len() - 1] &= mask;
+                }
+                -1 => {
+                    for idx in 0..row.len() - 1 {
+                        row[idx] = (row[idx] >> 1) | (row[idx + 1] << 63);
+                    }
+                    row[row.len() - 1] >>= 1;
+                }
+                _ => {}
+            }
+        }
+        shifted
+    }
+
+    /// One generation of a life-like automaton over the Moore neighbourhood.
+    /// A clear cell becomes set when its count of set neighbours is in
+    /// `birth`, and a set cell stays set when its count is in `survive`, so
+    /// Conway's rules are `life_step(&[3], &[2, 3])`.
+    #[must_use]
+    pub fn life_step(&self, birth: &[u8], survive: &[u8]) -> Self {
+        // Bit-sliced counters: bit `n` of every cell's count lives in
+        // `counts[n]`, and each neighbour is added with a ripple carry
+        let mut counts = vec![Self::new(self.width, self.height); 4];
+        for dir in Direction8::iter() {
+            let mut carry = self.shift(dir);
+            for plane in &mut counts {
+                let next_carry = &*plane & &carry;
+                *plane ^= &carry;
+                carry = next_carry;
+            }
+        }
+
+        let with_count = |count: u8| {
+            let mut matches = !&Self::new(self.width, self.height);
+            for (bit, plane) in counts.iter().enumerate() {
+                if count & (1 << bit) == 0 {
+                    matches &= &!plane;
+                } else {
+                    matches &= plane;
+                }
+            }
+            matches
+        };
+        let any_count = |counts: &[u8]| {
+            counts
+                .iter()
+                .fold(Self::new(self.width, self.height), |acc, &n| {
+                    &acc | &with_count(n)
+                })
+        };
+
+        let mut next = &!self & &any_count(birth);
+        next |= &(self & &any_count(survive));
+        next
+    }
+
+    fn assert_same_shape(&self, other: &Self) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "BitGrid dimensions differ"
+        );
+    }
+}
+
+macro_rules! bitwise_op {
+    ($op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl $op_assign<&BitGrid> for BitGrid {
+            fn $method_assign(&mut self, other: &BitGrid) {
+                self.assert_same_shape(other);
+                for (word, other) in self.words.iter_mut().zip(&other.words) {
+                    word.$method_assign(other);
+                }
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                let mut result = self.clone();
+                result.$method_assign(other);
+                result
+            }
+        }
+    };
+}
+
+bitwise_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+bitwise_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+bitwise_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut result = self.clone();
+        let mask = self.last_word_mask();
+        for row in result.words.chunks_mut(self.row_words()) {
+            for word in row.iter_mut() {
+                *word = !*word;
+            }
+            row[row.len() - 1] &= mask;
+        }
+        result
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        Self::from_grid(grid, |&cell| cell)
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid::from_fn_oriented(
+            bits.width,
+            bits.height,
+            bits.orientation,
+            |at| *bits.get(at).unwrap(),
+        )
+    }
+}
+
+impl Space for BitGrid {
+    type Coord = Coord;
+    type Cell = bool;
+
+    fn cell(&self, at: Coord) -> Option<&bool> {
+        self.get(at)
+    }
+
+    fn adjacent(&self, at: Coord) -> impl Iterator<Item = Coord> {
+        Direction::iter().map(move |dir| at + dir.vector())
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.height {
+            let y = self.row_index(row);
+            for x in 0..self.width {
+                let set = *self.get((x, y)).unwrap();
+                write!(fmt, "{}", if set { '#' } else { '.' })?;
+            }
+            writeln!(fmt)?;
+        }
+        Ok(())
+    }
+}
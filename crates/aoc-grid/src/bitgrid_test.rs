@@ -0,0 +1,122 @@
+use super::*;
+use std::collections::HashSet;
+
+/// Wider than a word so that rows span two of them
+fn scattered() -> BitGrid {
+    let mut bits = BitGrid::new(70, 4);
+    for at in [(0, 0), (63, 1), (64, 1), (69, 3), (5, 2), (69, 0)] {
+        bits.set(at, true);
+    }
+    bits
+}
+
+#[test]
+fn bitgrid_get_set() {
+    let mut bits = scattered();
+    assert_eq!(bits.count_ones(), 6);
+    assert_eq!(bits.get((63, 1)), Some(&true));
+    assert_eq!(bits.get((62, 1)), Some(&false));
+    assert_eq!(bits.get((70, 0)), None);
+    assert_eq!(bits.get((0, 4)), None);
+
+    bits.set((63, 1), false);
+    assert_eq!(bits.get((63, 1)), Some(&false));
+    assert_eq!(bits.count_ones(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Coordinate (70, 0) out of bounds")]
+fn bitgrid_set_out_of_bounds() {
+    BitGrid::new(70, 4).set((70, 0), true);
+}
+
+#[test]
+fn bitgrid_iter_ones_matches_iter() {
+    for orientation in [Orientation::YUp, Orientation::YDown] {
+        let bits = scattered().with_orientation(orientation);
+        let ones = bits.iter_ones().collect::<Vec<_>>();
+        let expected = bits
+            .iter()
+            .filter(|(_, set)| **set)
+            .map(|(at, _)| at)
+            .collect::<Vec<_>>();
+        assert_eq!(ones, expected);
+    }
+}
+
+#[test]
+fn bitgrid_matches_grid() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        true, false, false,
+        false, true, true,
+    ], 3);
+    let bits = BitGrid::from(&grid);
+    assert_eq!(bits.to_string(), "#..\n.##\n");
+    assert_eq!(Grid::from(&bits), grid);
+    for (at, cell) in grid.iter() {
+        assert_eq!(bits.get(at), Some(cell));
+        assert!(grid
+            .iter_all_neighbours(at)
+            .eq(bits.iter_all_neighbours(at)));
+        assert!(grid
+            .iter_cardinal_neighbours(at)
+            .eq(bits.iter_cardinal_neighbours(at)));
+    }
+}
+
+#[test]
+fn bitgrid_shift() {
+    let bits = scattered();
+    for dir in Direction8::iter() {
+        let shifted = bits.shift(dir);
+        let step = Orientation::YUp.vector(dir);
+        for (at, &set) in shifted.iter() {
+            let source = bits.get(at - step).copied().unwrap_or(false);
+            assert_eq!(set, source, "{dir:?} at {at}");
+        }
+    }
+    // Only (69, 3) on the top row falls off
+    let there_and_back = bits.shift(Direction::Up).shift(Direction::Down);
+    assert_eq!(there_and_back.count_ones(), 5);
+    assert_eq!(there_and_back.get((69, 3)), Some(&false));
+}
+
+#[test]
+fn bitgrid_boolean_ops() {
+    let bits = scattered();
+    let mut other = BitGrid::new(70, 4);
+    other.set((0, 0), true);
+    other.set((1, 0), true);
+
+    assert_eq!((&bits & &other).count_ones(), 1);
+    assert_eq!((&bits | &other).count_ones(), 7);
+    assert_eq!((&bits ^ &other).count_ones(), 6);
+    // Padding bits past the right edge must stay clear
+    assert_eq!((!&bits).count_ones(), 70 * 4 - 6);
+    assert_eq!(!&!&bits, bits);
+}
+
+#[test]
+fn bitgrid_life() {
+    let mut blinker = BitGrid::new(5, 5);
+    for x in 1..=3 {
+        blinker.set((x, 2), true);
+    }
+    let next = blinker.life_step(&[3], &[2, 3]);
+    assert_eq!(next.to_string(), ".....\n..#..\n..#..\n..#..\n.....\n");
+    assert_eq!(next.life_step(&[3], &[2, 3]), blinker);
+
+    // A glider on a closed board eventually settles into a block, which a
+    // hash set of seen states detects
+    let mut glider = BitGrid::new(6, 6).with_orientation(Orientation::YDown);
+    for at in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+        glider.set(at, true);
+    }
+    let mut seen = HashSet::new();
+    while seen.insert(glider.clone()) {
+        glider = glider.life_step(&[3], &[2, 3]);
+    }
+    assert_eq!(glider.count_ones(), 4);
+    assert_eq!(glider.life_step(&[3], &[2, 3]), glider);
+}
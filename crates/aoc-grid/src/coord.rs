@@ -1,6 +1,6 @@
 use std::{
     fmt::{Display, Formatter},
-    ops::Add,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
 };
 
 #[cfg(test)]
@@ -25,7 +25,7 @@ impl Display for Coord {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Vector {
     pub x: i64,
     pub y: i64,
@@ -56,3 +56,109 @@ impl Add<Vector> for Vector {
         }
     }
 }
+
+impl Coord {
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self - other).manhattan()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self - other).chebyshev()
+    }
+}
+
+impl Vector {
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev(self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    /// Rotate 90 degrees anticlockwise, with y increasing upward
+    #[must_use]
+    pub fn rotate_left(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotate 90 degrees clockwise, with y increasing upward
+    #[must_use]
+    pub fn rotate_right(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+impl Sub<Vector> for Coord {
+    type Output = Self;
+    fn sub(self, vector: Vector) -> Self {
+        self + -vector
+    }
+}
+
+impl Sub<Coord> for Coord {
+    type Output = Vector;
+    fn sub(self, other: Coord) -> Vector {
+        Vector {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl AddAssign<Vector> for Coord {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl SubAssign<Vector> for Coord {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub<Vector> for Vector {
+    type Output = Self;
+    fn sub(self, vector: Vector) -> Self {
+        self + -vector
+    }
+}
+
+impl AddAssign<Vector> for Vector {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl SubAssign<Vector> for Vector {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Self;
+    fn mul(self, scale: i64) -> Self {
+        Self {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
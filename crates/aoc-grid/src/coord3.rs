@@ -0,0 +1,149 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+#[cfg(test)]
+#[path = "coord3_test.rs"]
+mod coord3_test;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Vector3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl From<(i64, i64, i64)> for Coord3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<(i64, i64, i64)> for Vector3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Display for Coord3 {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        write!(fmt, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Coord3 {
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self - other).manhattan()
+    }
+
+    /// The six cells sharing a face with this one
+    pub fn face_neighbours(self) -> impl Iterator<Item = Self> {
+        Vector3::FACES.into_iter().map(move |offset| self + offset)
+    }
+
+    /// The 26 cells sharing a face, edge or corner with this one
+    pub fn all_neighbours(self) -> impl Iterator<Item = Self> {
+        Vector3::all_offsets().map(move |offset| self + offset)
+    }
+}
+
+impl Vector3 {
+    const FACES: [Self; 6] = [
+        Self { x: 1, y: 0, z: 0 },
+        Self { x: -1, y: 0, z: 0 },
+        Self { x: 0, y: 1, z: 0 },
+        Self { x: 0, y: -1, z: 0 },
+        Self { x: 0, y: 0, z: 1 },
+        Self { x: 0, y: 0, z: -1 },
+    ];
+
+    pub fn manhattan(self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    fn all_offsets() -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|x| {
+                (-1..=1).flat_map(move |y| (-1..=1).map(move |z| (x, y, z)))
+            })
+            .filter(|&offset| offset != (0, 0, 0))
+            .map(Self::from)
+    }
+}
+
+impl Add<Vector3> for Coord3 {
+    type Output = Self;
+    fn add(self, vector: Vector3) -> Self {
+        Self {
+            x: self.x + vector.x,
+            y: self.y + vector.y,
+            z: self.z + vector.z,
+        }
+    }
+}
+
+impl Sub<Vector3> for Coord3 {
+    type Output = Self;
+    fn sub(self, vector: Vector3) -> Self {
+        self + -vector
+    }
+}
+
+impl Sub<Coord3> for Coord3 {
+    type Output = Vector3;
+    fn sub(self, other: Coord3) -> Vector3 {
+        Vector3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl Add<Vector3> for Vector3 {
+    type Output = Self;
+    fn add(self, vector: Vector3) -> Self {
+        Self {
+            x: self.x + vector.x,
+            y: self.y + vector.y,
+            z: self.z + vector.z,
+        }
+    }
+}
+
+impl Sub<Vector3> for Vector3 {
+    type Output = Self;
+    fn sub(self, vector: Vector3) -> Self {
+        self + -vector
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Mul<i64> for Vector3 {
+    type Output = Self;
+    fn mul(self, scale: i64) -> Self {
+        Self {
+            x: self.x * scale,
+            y: self.y * scale,
+            z: self.z * scale,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn coord3_arithmetic() {
+    let coord = Coord3::from((1, 2, 3));
+    let vector = Vector3::from((4, -5, 6));
+    assert_eq!(coord + vector, Coord3::from((5, -3, 9)));
+    assert_eq!(coord - vector, Coord3::from((-3, 7, -3)));
+    assert_eq!((coord + vector) - coord, vector);
+    assert_eq!(vector + vector, vector * 2);
+    assert_eq!(vector - vector, Vector3::from((0, 0, 0)));
+    assert_eq!(-vector, Vector3::from((-4, 5, -6)));
+}
+
+#[test]
+fn coord3_manhattan() {
+    let a = Coord3::from((1, -2, 3));
+    let b = Coord3::from((-1, 2, 0));
+    assert_eq!(a.manhattan(b), 9);
+    assert_eq!(b.manhattan(a), 9);
+    assert_eq!(Vector3::from((-3, 0, 4)).manhattan(), 7);
+}
+
+#[test]
+fn coord3_neighbours() {
+    let coord = Coord3::from((0, 0, 0));
+    let faces = coord.face_neighbours().collect::<Vec<_>>();
+    assert_eq!(faces.len(), 6);
+    assert!(faces.iter().all(|neigh| neigh.manhattan(coord) == 1));
+
+    let all = coord.all_neighbours().collect::<Vec<_>>();
+    assert_eq!(all.len(), 26);
+    assert!(!all.contains(&coord));
+    assert!(faces.iter().all(|face| all.contains(face)));
+    assert!(all.contains(&(1, 1, 1).into()));
+}
@@ -13,3 +13,52 @@ fn vector_add_vector() {
     let vector = Vector::from((13, -8));
     assert_eq!(vector1 + vector, Vector::from((27, -3)));
 }
+
+#[test]
+fn coord_sub() {
+    let coord = Coord::from((14, 5));
+    let vector = Vector::from((13, -8));
+    assert_eq!(coord - vector, Coord::from((1, 13)));
+    assert_eq!(Coord::from((27, -3)) - coord, vector);
+}
+
+#[test]
+fn coord_assign_ops() {
+    let mut coord = Coord::from((1, 1));
+    coord += Vector::from((2, 3));
+    assert_eq!(coord, Coord::from((3, 4)));
+    coord -= Vector::from((5, 5));
+    assert_eq!(coord, Coord::from((-2, -1)));
+
+    let mut vector = Vector::from((1, 1));
+    vector += Vector::from((1, 2));
+    vector -= Vector::from((4, 0));
+    assert_eq!(vector, Vector::from((-2, 3)));
+}
+
+#[test]
+fn vector_scale_negate() {
+    let vector = Vector::from((3, -2));
+    assert_eq!(vector * 4, Vector::from((12, -8)));
+    assert_eq!(-vector, Vector::from((-3, 2)));
+    assert_eq!(vector - vector, Vector::from((0, 0)));
+}
+
+#[test]
+fn distances() {
+    let a = Coord::from((1, -2));
+    let b = Coord::from((-3, 5));
+    assert_eq!(a.manhattan(b), 11);
+    assert_eq!(a.chebyshev(b), 7);
+    assert_eq!(b.manhattan(a), 11);
+    assert_eq!(Vector::from((-4, 2)).chebyshev(), 4);
+}
+
+#[test]
+fn vector_rotation() {
+    let vector = Vector::from((10, 4));
+    assert_eq!(vector.rotate_left(), Vector::from((-4, 10)));
+    assert_eq!(vector.rotate_right(), Vector::from((4, -10)));
+    assert_eq!(vector.rotate_left().rotate_right(), vector);
+    assert_eq!(vector.rotate_right().rotate_right(), -vector);
+}
@@ -0,0 +1,258 @@
+use crate::coord::Vector;
+
+#[cfg(test)]
+#[path = "direction_test.rs"]
+mod direction_test;
+
+/// How coordinates map onto the grid as it is printed
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// (0, 0) is the bottom-left cell and y increases upward
+    #[default]
+    YUp,
+    /// (0, 0) is the top-left cell and y increases downward, as on screen
+    YDown,
+}
+
+impl Orientation {
+    /// The step that moves one cell in `direction` as seen on the page
+    pub fn vector(self, direction: impl Into<Direction8>) -> Vector {
+        let vector = direction.into().vector();
+        match self {
+            Self::YUp => vector,
+            Self::YDown => (vector.x, -vector.y).into(),
+        }
+    }
+}
+
+/// A step across a grid: either a `Vector` in the grid's own coordinates, or
+/// a direction as seen on the page
+pub trait Step {
+    fn to_vector(self, orientation: Orientation) -> Vector;
+}
+
+impl Step for Vector {
+    fn to_vector(self, _: Orientation) -> Vector {
+        self
+    }
+}
+
+impl Step for Direction {
+    fn to_vector(self, orientation: Orientation) -> Vector {
+        orientation.vector(self)
+    }
+}
+
+impl Step for Direction8 {
+    fn to_vector(self, orientation: Orientation) -> Vector {
+        orientation.vector(self)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+impl Direction {
+    pub fn vector(self) -> Vector {
+        match self {
+            Self::Up => (0, 1).into(),
+            Self::Left => (-1, 0).into(),
+            Self::Down => (0, -1).into(),
+            Self::Right => (1, 0).into(),
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        [Self::Up, Self::Left, Self::Down, Self::Right].into_iter()
+    }
+
+    /// The direction of a unit step, if `vector` is one
+    pub fn from_vector(vector: impl Into<Vector>) -> Option<Self> {
+        let vector = vector.into();
+        Self::iter().find(|dir| dir.vector() == vector)
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Left => Self::Right,
+            Self::Down => Self::Up,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Compass direction including the diagonals
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Self::Up => (0, 1).into(),
+            Self::UpRight => (1, 1).into(),
+            Self::Right => (1, 0).into(),
+            Self::DownRight => (1, -1).into(),
+            Self::Down => (0, -1).into(),
+            Self::DownLeft => (-1, -1).into(),
+            Self::Left => (-1, 0).into(),
+            Self::UpLeft => (-1, 1).into(),
+        }
+    }
+
+    /// Iterates clockwise starting from `Up`
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let vector = self.vector();
+        vector.x != 0 && vector.y != 0
+    }
+
+    /// Rotate clockwise by 45 degrees
+    pub fn rotate_cw(self) -> Self {
+        self.rotate_by(1)
+    }
+
+    /// Rotate anticlockwise by 45 degrees
+    pub fn rotate_ccw(self) -> Self {
+        self.rotate_by(-1)
+    }
+
+    /// Rotate by `steps` multiples of 45 degrees, positive being clockwise
+    pub fn rotate_by(self, steps: i64) -> Self {
+        let idx = Self::CLOCKWISE.iter().position(|dir| *dir == self).unwrap();
+        let idx = (idx as i64 + steps).rem_euclid(8);
+        Self::CLOCKWISE[idx as usize]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate_by(4)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Self::Up,
+            Direction::Left => Self::Left,
+            Direction::Down => Self::Down,
+            Direction::Right => Self::Right,
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(dir: Direction) -> Self {
+        dir.vector()
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(dir: Direction8) -> Self {
+        dir.vector()
+    }
+}
+
+/// Which cells count as adjacent when querying a grid
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// The four cardinal neighbours
+    VonNeumann,
+    /// All eight surrounding cells, including diagonals
+    Moore,
+    /// Arbitrary offsets relative to the queried cell, in the grid's own
+    /// coordinates
+    Custom(Vec<Vector>),
+}
+
+impl Neighbourhood {
+    const VON_NEUMANN: [Vector; 4] = [
+        Vector { x: 0, y: 1 },
+        Vector { x: -1, y: 0 },
+        Vector { x: 0, y: -1 },
+        Vector { x: 1, y: 0 },
+    ];
+    const MOORE: [Vector; 8] = [
+        Vector { x: 0, y: 1 },
+        Vector { x: 1, y: 1 },
+        Vector { x: 1, y: 0 },
+        Vector { x: 1, y: -1 },
+        Vector { x: 0, y: -1 },
+        Vector { x: -1, y: -1 },
+        Vector { x: -1, y: 0 },
+        Vector { x: -1, y: 1 },
+    ];
+
+    /// Offsets for a grid with y pointing up. The built-in neighbourhoods are
+    /// listed in the same order as `Direction::iter` and `Direction8::iter`.
+    pub fn offsets(&self) -> &[Vector] {
+        match self {
+            Self::VonNeumann => &Self::VON_NEUMANN,
+            Self::Moore => &Self::MOORE,
+            Self::Custom(offsets) => offsets,
+        }
+    }
+
+    /// Offsets for a grid with the given orientation. The built-in
+    /// neighbourhoods are flipped to keep following the directions as seen
+    /// on the page, while custom offsets are used as given.
+    pub fn oriented_offsets(
+        &self,
+        orientation: Orientation,
+    ) -> impl Iterator<Item = Vector> + '_ {
+        let flip = orientation == Orientation::YDown
+            && !matches!(self, Self::Custom(_));
+        self.offsets().iter().map(move |&offset| {
+            if flip {
+                (offset.x, -offset.y).into()
+            } else {
+                offset
+            }
+        })
+    }
+}
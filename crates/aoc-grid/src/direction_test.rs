@@ -0,0 +1,69 @@
+use super::*;
+
+#[test]
+fn direction_turns() {
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Left.opposite(), Direction::Right);
+    for dir in Direction::iter() {
+        assert_eq!(dir.turn_left().turn_right(), dir);
+        assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+        assert_eq!(dir.turn_left().vector(), dir.vector().rotate_left());
+        assert_eq!(dir.turn_right().vector(), dir.vector().rotate_right());
+        assert_eq!(dir.opposite().vector(), -dir.vector());
+    }
+}
+
+#[test]
+fn direction_from_vector() {
+    for dir in Direction::iter() {
+        assert_eq!(Direction::from_vector(dir.vector()), Some(dir));
+    }
+    assert_eq!(Direction::from_vector((0, -1)), Some(Direction::Down));
+    assert_eq!(Direction::from_vector((0, 2)), None);
+    assert_eq!(Direction::from_vector((1, 1)), None);
+}
+
+#[test]
+fn direction8_rotation() {
+    assert_eq!(Direction8::Up.rotate_cw(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.rotate_ccw(), Direction8::UpLeft);
+    assert_eq!(Direction8::UpLeft.rotate_cw(), Direction8::Up);
+    assert_eq!(Direction8::Right.rotate_by(-10), Direction8::Up);
+    assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+    for dir in Direction8::iter() {
+        assert_eq!(dir.rotate_by(8), dir);
+        assert_eq!(dir.rotate_cw().rotate_ccw(), dir);
+    }
+}
+
+#[test]
+fn direction8_vectors() {
+    assert_eq!(Direction8::iter().count(), 8);
+    assert_eq!(
+        Direction8::iter().filter(|dir| dir.is_diagonal()).count(),
+        4
+    );
+    for dir in Direction8::iter() {
+        let vector = dir.vector();
+        let opposite = dir.opposite().vector();
+        assert_eq!(vector + opposite, Vector::from((0, 0)));
+    }
+    for dir in Direction::iter() {
+        assert_eq!(Direction8::from(dir).vector(), dir.vector());
+    }
+}
+
+#[test]
+fn neighbourhood_offsets_match_directions() {
+    assert!(Neighbourhood::VonNeumann
+        .offsets()
+        .iter()
+        .copied()
+        .eq(Direction::iter().map(Direction::vector)));
+    assert!(Neighbourhood::Moore
+        .offsets()
+        .iter()
+        .copied()
+        .eq(Direction8::iter().map(Direction8::vector)));
+}
@@ -0,0 +1,120 @@
+use crate::{coord::Coord, Direction};
+
+#[cfg(test)]
+#[path = "geometry_test.rs"]
+mod geometry_test;
+
+/// Closed polygon on the integer lattice. The last vertex joins back up to
+/// the first, and consecutive vertices may be collinear, so a loop traced one
+/// cell at a time can be used directly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Coord>,
+}
+
+impl Polygon {
+    pub fn new(vertices: impl Into<Vec<Coord>>) -> Self {
+        let mut vertices = vertices.into();
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    /// Trace a polygon from `start` by moving the given distance in each
+    /// direction in turn, as in a dig plan
+    pub fn from_steps<I>(start: impl Into<Coord>, steps: I) -> Self
+    where
+        I: IntoIterator<Item = (Direction, i64)>,
+    {
+        let mut at = start.into();
+        let mut vertices = vec![at];
+        for (dir, distance) in steps {
+            at += dir.vector() * distance;
+            vertices.push(at);
+        }
+        Self::new(vertices)
+    }
+
+    pub fn vertices(&self) -> &[Coord] {
+        &self.vertices
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the enclosed area, which is always an integer for lattice
+    /// polygons. Computed with the shoelace formula.
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            .abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// Number of lattice points lying on the edges
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+            .sum()
+    }
+
+    /// Number of lattice points strictly inside, from Pick's theorem
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// Whether `at` lies on one of the edges
+    pub fn on_boundary(&self, at: impl Into<Coord>) -> bool {
+        let at = at.into();
+        self.edges().any(|(a, b)| {
+            let cross = (b.x - a.x) * (at.y - a.y) - (b.y - a.y) * (at.x - a.x);
+            cross == 0
+                && at.x >= a.x.min(b.x)
+                && at.x <= a.x.max(b.x)
+                && at.y >= a.y.min(b.y)
+                && at.y <= a.y.max(b.y)
+        })
+    }
+
+    /// Whether `at` lies strictly inside. Points on the boundary are not
+    /// contained.
+    pub fn contains(&self, at: impl Into<Coord>) -> bool {
+        let at = at.into();
+        if self.on_boundary(at) {
+            return false;
+        }
+
+        // Count crossings of a ray heading in the +x direction
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > at.y) == (b.y > at.y) {
+                continue;
+            }
+            // Is `at` left of the edge's intersection with the ray? Scale
+            // through by dy, taking care over its sign.
+            let dy = b.y - a.y;
+            let lhs = (at.x - a.x) * dy;
+            let rhs = (at.y - a.y) * (b.x - a.x);
+            if (dy > 0 && lhs < rhs) || (dy < 0 && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
@@ -0,0 +1,104 @@
+use super::*;
+
+fn square() -> Polygon {
+    Polygon::new([(0, 0), (2, 0), (2, 2), (0, 2)].map(Coord::from))
+}
+
+#[test]
+fn square_measurements() {
+    let square = square();
+    assert_eq!(square.double_area(), 8);
+    assert_eq!(square.area(), 4.0);
+    assert_eq!(square.boundary_points(), 8);
+    assert_eq!(square.interior_points(), 1);
+}
+
+#[test]
+fn closing_vertex_and_orientation() {
+    let closed =
+        Polygon::new([(0, 0), (0, 2), (2, 2), (2, 0), (0, 0)].map(Coord::from));
+    assert_eq!(closed.vertices().len(), 4);
+    assert_eq!(closed.double_area(), square().double_area());
+    assert_eq!(closed.interior_points(), 1);
+}
+
+#[test]
+fn unit_step_trace() {
+    // A 3x3 ring of cells traced one step at a time encloses the middle
+    let ring = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+        (1, 2),
+        (0, 2),
+        (0, 1),
+    ];
+    let polygon = Polygon::new(ring.map(Coord::from));
+    assert_eq!(polygon.boundary_points(), 8);
+    assert_eq!(polygon.interior_points(), 1);
+}
+
+#[test]
+fn dig_plan() {
+    use Direction::*;
+    let plan = [
+        (Right, 6),
+        (Down, 5),
+        (Left, 2),
+        (Down, 2),
+        (Right, 2),
+        (Down, 2),
+        (Left, 5),
+        (Up, 2),
+        (Left, 1),
+        (Up, 2),
+        (Right, 2),
+        (Up, 3),
+        (Left, 2),
+        (Up, 2),
+    ];
+    let lagoon = Polygon::from_steps((0, 0), plan);
+    assert_eq!(lagoon.vertices().len(), 14);
+    assert_eq!(lagoon.boundary_points(), 38);
+    assert_eq!(lagoon.interior_points() + lagoon.boundary_points(), 62);
+}
+
+#[test]
+fn point_in_polygon() {
+    let square = square();
+    assert!(square.contains((1, 1)));
+    assert!(!square.contains((0, 1)));
+    assert!(square.on_boundary((0, 1)));
+    assert!(square.on_boundary((2, 2)));
+    assert!(!square.contains((3, 1)));
+    assert!(!square.contains((-1, 0)));
+    assert!(!square.on_boundary((3, 1)));
+
+    // U shape with a notch cut down from the top
+    let u_shape = Polygon::new(
+        [
+            (0, 0),
+            (6, 0),
+            (6, 4),
+            (4, 4),
+            (4, 2),
+            (2, 2),
+            (2, 4),
+            (0, 4),
+        ]
+        .map(Coord::from),
+    );
+    assert!(u_shape.contains((1, 3)));
+    assert!(u_shape.contains((5, 3)));
+    assert!(u_shape.contains((3, 1)));
+    assert!(!u_shape.contains((3, 3)));
+    assert!(!u_shape.contains((3, 2)));
+
+    let interior = (0..=6)
+        .flat_map(|x| (0..=4).map(move |y| (x, y)))
+        .filter(|&at| u_shape.contains(at))
+        .count();
+    assert_eq!(interior as i64, u_shape.interior_points());
+}
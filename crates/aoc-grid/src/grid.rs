@@ -1,15 +1,22 @@
-use crate::{coord::Coord, Direction};
-use std::fmt::Display;
+use crate::{
+    coord::{Coord, Vector},
+    Direction, Direction8, Neighbourhood, Orientation, Step,
+};
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
 
 #[cfg(test)]
 #[path = "grid_test.rs"]
 mod grid_test;
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     inner: Vec<T>,
     width: i64,
     height: i64,
+    orientation: Orientation,
 }
 
 impl<T: Default> Grid<T> {
@@ -22,11 +29,44 @@ impl<T: Default> Grid<T> {
             width,
             height,
             inner: (0..vec_len).map(|_| T::default()).collect(),
+            orientation: Orientation::default(),
         }
     }
 }
 
 impl<T> Grid<T> {
+    /// Build a grid by calling `f` with the coordinate of every cell
+    pub fn from_fn<F>(width: i64, height: i64, f: F) -> Self
+    where
+        F: FnMut(Coord) -> T,
+    {
+        Self::from_fn_oriented(width, height, Orientation::default(), f)
+    }
+
+    /// As `from_fn`, with `f` seeing coordinates in the given orientation
+    pub fn from_fn_oriented<F>(
+        width: i64,
+        height: i64,
+        orientation: Orientation,
+        f: F,
+    ) -> Self
+    where
+        F: FnMut(Coord) -> T,
+    {
+        assert!(width > 0 && height >= 0);
+        let vec_len = usize::try_from(width * height).unwrap();
+        let inner = (0..vec_len)
+            .map(|idx| idx_to_coord(width, height, orientation, idx))
+            .map(f)
+            .collect();
+        Self {
+            inner,
+            width,
+            height,
+            orientation,
+        }
+    }
+
     #[must_use]
     pub fn new(data: impl Into<Vec<T>>, width: i64) -> Self {
         assert!(width > 0);
@@ -44,9 +84,22 @@ impl<T> Grid<T> {
             inner: data,
             width,
             height,
+            orientation: Orientation::default(),
         }
     }
 
+    /// Reinterpret coordinates without moving any cells. The grid prints the
+    /// same, but coordinates taken from it beforehand may name other cells.
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
     pub fn width(&self) -> i64 {
         self.width
     }
@@ -54,30 +107,34 @@ impl<T> Grid<T> {
         self.height
     }
 
-    pub fn get(&self, at: impl Into<Coord>) -> Option<&T> {
-        let at = at.into();
+    fn offset(&self, at: Coord) -> Option<usize> {
         if at.x < 0 || at.y < 0 || at.x >= self.width || at.y >= self.height {
             return None;
         }
 
-        let offset = at.x + self.height * (self.height - 1 - at.y);
-        let offset: usize =
-            offset.try_into().expect("Offest out of range for usize");
-        self.inner.get(offset)
+        let offset = at.x + self.width * self.row_index(at.y);
+        Some(offset.try_into().expect("Offest out of range for usize"))
     }
 
-    pub fn set(&mut self, at: impl Into<Coord>, new: T) {
-        let at = at.into();
-        if at.x < 0 || at.y < 0 || at.x >= self.width || at.y >= self.height {
+    fn offset_or_panic(&self, at: Coord) -> usize {
+        self.offset(at).unwrap_or_else(|| {
             panic!(
                 "Coordinate {} out of bounds in a grid of dimension ({}, {})",
                 at, self.width, self.height
-            );
-        }
+            )
+        })
+    }
 
-        let offset = at.x + self.height * (self.height - 1 - at.y);
-        let offset: usize =
-            offset.try_into().expect("Offest out of range for usize");
+    pub fn get(&self, at: impl Into<Coord>) -> Option<&T> {
+        self.offset(at.into()).map(|offset| &self.inner[offset])
+    }
+
+    pub fn get_mut(&mut self, at: impl Into<Coord>) -> Option<&mut T> {
+        self.offset(at.into()).map(|offset| &mut self.inner[offset])
+    }
+
+    pub fn set(&mut self, at: impl Into<Coord>, new: T) {
+        let offset = self.offset_or_panic(at.into());
         self.inner[offset] = new;
     }
 
@@ -86,20 +143,76 @@ impl<T> Grid<T> {
         self.inner.chunks(width)
     }
 
+    /// Columns from left to right, each read from top to bottom
+    pub fn cols(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |x| self.col(x).unwrap())
+    }
+
+    /// The cells with the given y coordinate, from left to right
+    pub fn row(&self, y: i64) -> Option<&[T]> {
+        if y < 0 || y >= self.height {
+            return None;
+        }
+        let row_idx = usize::try_from(self.row_index(y)).unwrap();
+        self.rows().nth(row_idx)
+    }
+
+    /// The cells with the given x coordinate, from top to bottom
+    pub fn col(&self, x: i64) -> Option<impl Iterator<Item = &T> + '_> {
+        if x < 0 || x >= self.width {
+            return None;
+        }
+        let x = usize::try_from(x).unwrap();
+        Some(self.rows().map(move |row| &row[x]))
+    }
+
+    /// Position in `rows()` of the row with the given y coordinate, and the
+    /// y coordinate of a given position in `rows()` (the mapping is its own
+    /// inverse)
+    pub(crate) fn row_index(&self, y: i64) -> i64 {
+        match self.orientation {
+            Orientation::YUp => self.height - 1 - y,
+            Orientation::YDown => y,
+        }
+    }
+
+    /// Walk from `at` (exclusive) in steps of `step` until leaving the grid
+    pub fn ray(
+        &self,
+        at: impl Into<Coord>,
+        step: impl Step,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let step = step.to_vector(self.orientation);
+        assert!(step != Vector::from((0, 0)), "Ray step must be non-zero");
+        std::iter::successors(Some(at.into()), move |&at| Some(at + step))
+            .skip(1)
+            .map_while(|at| self.get(at).map(|val| (at, val)))
+    }
+
     pub fn get_neighbour(
         &self,
         at: impl Into<Coord>,
         direction: Direction,
     ) -> Option<(Coord, &T)> {
-        let at = at.into() + direction.vector();
+        let at = at.into() + self.orientation.vector(direction);
         self.get(at).map(|val| (at, val))
     }
 
-    fn idx_to_coord(&self, idx: usize) -> Coord {
-        let idx: i64 = idx.try_into().unwrap();
-        let x = idx % self.width;
-        let y = self.height - 1 - (idx / self.height);
-        (x, y).into()
+    /// Cells in storage order, matching the order of `iter`
+    #[cfg(feature = "rayon")]
+    pub(crate) fn cells(&self) -> &[T] {
+        &self.inner
+    }
+
+    #[cfg(feature = "rayon")]
+    pub(crate) fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.inner
+    }
+
+    pub(crate) fn idx_to_coord(&self, idx: usize) -> Coord {
+        idx_to_coord(self.width, self.height, self.orientation, idx)
     }
 
     pub fn find<F>(&self, mut condition: F) -> Option<Coord>
@@ -121,12 +234,125 @@ impl<T> Grid<T> {
         Direction::iter().filter_map(move |dir| self.get_neighbour(at, dir))
     }
 
+    pub fn iter_all_neighbours(
+        &self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let at = at.into();
+        Direction8::iter().filter_map(move |dir| {
+            let at = at + self.orientation.vector(dir);
+            self.get(at).map(|val| (at, val))
+        })
+    }
+
+    pub fn iter_neighbours<'a>(
+        &'a self,
+        at: impl Into<Coord>,
+        kind: &'a Neighbourhood,
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        let at = at.into();
+        kind.oriented_offsets(self.orientation)
+            .filter_map(move |offset| {
+                let at = at + offset;
+                self.get(at).map(|val| (at, val))
+            })
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
         self.inner
             .iter()
             .enumerate()
             .map(|(idx, value)| (self.idx_to_coord(idx), value))
     }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let (width, height) = (self.width, self.height);
+        let orientation = self.orientation;
+        self.inner.iter_mut().enumerate().map(move |(idx, value)| {
+            (idx_to_coord(width, height, orientation, idx), value)
+        })
+    }
+
+    /// Build a grid of the same shape by applying `f` to every cell
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            inner: self.inner.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+            orientation: self.orientation,
+        }
+    }
+
+    /// As `map`, consuming the grid so cells can be moved out
+    pub fn into_map<U, F>(self, f: F) -> Grid<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Grid {
+            inner: self.inner.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+            orientation: self.orientation,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Change the dimensions, keeping every cell at the same coordinate.
+    /// Cells outside the old grid are filled with `fill`.
+    #[must_use]
+    pub fn resize(&self, width: i64, height: i64, fill: T) -> Self {
+        Self::from_fn_oriented(width, height, self.orientation, |at| {
+            self.get(at).unwrap_or(&fill).clone()
+        })
+    }
+
+    /// Surround the grid with a border `amount` cells thick. Coordinates of
+    /// existing cells increase by `amount` along both axes.
+    #[must_use]
+    pub fn pad(&self, amount: i64, fill: T) -> Self {
+        let offset = Vector::from((amount, amount));
+        Self::from_fn_oriented(
+            self.width + 2 * amount,
+            self.height + 2 * amount,
+            self.orientation,
+            |at| self.get(at - offset).unwrap_or(&fill).clone(),
+        )
+    }
+}
+
+pub(crate) fn idx_to_coord(
+    width: i64,
+    height: i64,
+    orientation: Orientation,
+    idx: usize,
+) -> Coord {
+    let idx: i64 = idx.try_into().unwrap();
+    let x = idx % width;
+    let row = idx / width;
+    let y = match orientation {
+        Orientation::YUp => height - 1 - row,
+        Orientation::YDown => row,
+    };
+    (x, y).into()
+}
+
+impl<T, C: Into<Coord>> Index<C> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: C) -> &T {
+        &self.inner[self.offset_or_panic(at.into())]
+    }
+}
+
+impl<T, C: Into<Coord>> IndexMut<C> for Grid<T> {
+    fn index_mut(&mut self, at: C) -> &mut T {
+        let offset = self.offset_or_panic(at.into());
+        &mut self.inner[offset]
+    }
 }
 
 impl<T> Display for Grid<T>
@@ -0,0 +1,241 @@
+use crate::coord3::Coord3;
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "grid3_test.rs"]
+mod grid3_test;
+
+/// Dense volumetric grid. Cells are stored x-fastest, then y, then z.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid3<T> {
+    inner: Vec<T>,
+    width: i64,
+    height: i64,
+    depth: i64,
+}
+
+impl<T: Default> Grid3<T> {
+    pub fn new_default(width: i64, height: i64, depth: i64) -> Self {
+        let vec_len = [width, height, depth]
+            .into_iter()
+            .map(|dim| usize::try_from(dim).unwrap())
+            .try_fold(1_usize, usize::checked_mul)
+            .unwrap();
+        Self {
+            width,
+            height,
+            depth,
+            inner: (0..vec_len).map(|_| T::default()).collect(),
+        }
+    }
+}
+
+impl<T> Grid3<T> {
+    #[must_use]
+    pub fn new(data: impl Into<Vec<T>>, width: i64, height: i64) -> Self {
+        assert!(width > 0 && height > 0);
+        let data = data.into();
+        let data_len: i64 =
+            data.len().try_into().expect("Data len exceeds i64::MAX");
+        assert_eq!(
+            data_len % (width * height),
+            0,
+            "Data length is not a multiple of width * height"
+        );
+        let depth = data_len / (width * height);
+
+        Self {
+            inner: data,
+            width,
+            height,
+            depth,
+        }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+    pub fn depth(&self) -> i64 {
+        self.depth
+    }
+
+    fn offset(&self, at: Coord3) -> Option<usize> {
+        if at.x < 0
+            || at.y < 0
+            || at.z < 0
+            || at.x >= self.width
+            || at.y >= self.height
+            || at.z >= self.depth
+        {
+            return None;
+        }
+        let offset = at.x + self.width * (at.y + self.height * at.z);
+        Some(offset.try_into().expect("Offset out of range for usize"))
+    }
+
+    fn idx_to_coord(&self, idx: usize) -> Coord3 {
+        let idx: i64 = idx.try_into().unwrap();
+        let x = idx % self.width;
+        let y = (idx / self.width) % self.height;
+        let z = idx / (self.width * self.height);
+        (x, y, z).into()
+    }
+
+    pub fn get(&self, at: impl Into<Coord3>) -> Option<&T> {
+        self.offset(at.into()).map(|offset| &self.inner[offset])
+    }
+
+    pub fn set(&mut self, at: impl Into<Coord3>, new: T) {
+        let at = at.into();
+        let Some(offset) = self.offset(at) else {
+            panic!(
+                "Coordinate {} out of bounds in a grid of dimension ({}, {}, {})",
+                at, self.width, self.height, self.depth
+            );
+        };
+        self.inner[offset] = new;
+    }
+
+    pub fn find<F>(&self, condition: F) -> Option<Coord3>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner
+            .iter()
+            .position(condition)
+            .map(|idx| self.idx_to_coord(idx))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord3, &T)> {
+        self.inner
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| (self.idx_to_coord(idx), value))
+    }
+
+    pub fn iter_face_neighbours(
+        &self,
+        at: impl Into<Coord3>,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        at.into()
+            .face_neighbours()
+            .filter_map(|at| self.get(at).map(|val| (at, val)))
+    }
+
+    pub fn iter_all_neighbours(
+        &self,
+        at: impl Into<Coord3>,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        at.into()
+            .all_neighbours()
+            .filter_map(|at| self.get(at).map(|val| (at, val)))
+    }
+}
+
+/// Unbounded volumetric grid which only stores the cells that have been
+/// written
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid3<T> {
+    inner: HashMap<Coord3, T>,
+    bounds: Option<(Coord3, Coord3)>,
+}
+
+impl<T> Default for SparseGrid3<T> {
+    fn default() -> Self {
+        Self {
+            inner: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid3<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Minimum and maximum corners of every cell ever written. The box does
+    /// not shrink when cells are removed.
+    pub fn bounds(&self) -> Option<(Coord3, Coord3)> {
+        self.bounds
+    }
+
+    pub fn get(&self, at: impl Into<Coord3>) -> Option<&T> {
+        self.inner.get(&at.into())
+    }
+
+    pub fn get_mut(&mut self, at: impl Into<Coord3>) -> Option<&mut T> {
+        self.inner.get_mut(&at.into())
+    }
+
+    pub fn set(&mut self, at: impl Into<Coord3>, new: T) {
+        let at = at.into();
+        self.bounds = Some(match self.bounds {
+            None => (at, at),
+            Some((min, max)) => (
+                (min.x.min(at.x), min.y.min(at.y), min.z.min(at.z)).into(),
+                (max.x.max(at.x), max.y.max(at.y), max.z.max(at.z)).into(),
+            ),
+        });
+        self.inner.insert(at, new);
+    }
+
+    pub fn remove(&mut self, at: impl Into<Coord3>) -> Option<T> {
+        self.inner.remove(&at.into())
+    }
+
+    pub fn find<F>(&self, mut condition: F) -> Option<Coord3>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner
+            .iter()
+            .find(|(_, cell)| condition(cell))
+            .map(|(at, _)| *at)
+    }
+
+    /// Occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord3, &T)> {
+        self.inner.iter().map(|(at, value)| (*at, value))
+    }
+
+    pub fn iter_face_neighbours(
+        &self,
+        at: impl Into<Coord3>,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        at.into()
+            .face_neighbours()
+            .filter_map(|at| self.get(at).map(|val| (at, val)))
+    }
+
+    pub fn iter_all_neighbours(
+        &self,
+        at: impl Into<Coord3>,
+    ) -> impl Iterator<Item = (Coord3, &T)> {
+        at.into()
+            .all_neighbours()
+            .filter_map(|at| self.get(at).map(|val| (at, val)))
+    }
+}
+
+impl<T> FromIterator<(Coord3, T)> for SparseGrid3<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord3, T)>>(iter: I) -> Self {
+        let mut sparse = Self::new();
+        for (at, value) in iter {
+            sparse.set(at, value);
+        }
+        sparse
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn make_grid3() {
+    let grid = Grid3::new([0_u8; 24], 2, 3);
+    assert_eq!((grid.width(), grid.height(), grid.depth()), (2, 3, 4));
+}
+
+#[test]
+#[should_panic(expected = "Data length is not a multiple of width * height")]
+fn grid3_invalid_dimensions() {
+    let _ = Grid3::new([0_u8; 10], 2, 3);
+}
+
+#[test]
+fn grid3_get_set_find() {
+    let mut grid = Grid3::<u8>::new_default(2, 3, 4);
+    assert_eq!(grid.get((1, 2, 3)), Some(&0));
+    assert!(grid.get((2, 0, 0)).is_none());
+    assert!(grid.get((0, 0, -1)).is_none());
+
+    grid.set((1, 2, 3), 7);
+    grid.set((1, 0, 2), 5);
+    assert_eq!(grid.get((1, 2, 3)), Some(&7));
+    assert_eq!(grid.find(|cell| *cell == 7), Some((1, 2, 3).into()));
+    assert_eq!(grid.find(|cell| *cell == 5), Some((1, 0, 2).into()));
+
+    for (at, value) in grid.iter() {
+        assert_eq!(grid.get(at), Some(value));
+    }
+    assert_eq!(grid.iter().count(), 24);
+}
+
+#[test]
+#[should_panic(expected = "Coordinate (0, 3, 0) out of bounds")]
+fn grid3_set_out_of_bounds() {
+    let mut grid = Grid3::<u8>::new_default(2, 3, 4);
+    grid.set((0, 3, 0), 1);
+}
+
+#[test]
+fn grid3_neighbours() {
+    let grid = Grid3::<u8>::new_default(3, 3, 3);
+    assert_eq!(grid.iter_face_neighbours((1, 1, 1)).count(), 6);
+    assert_eq!(grid.iter_all_neighbours((1, 1, 1)).count(), 26);
+    assert_eq!(grid.iter_face_neighbours((0, 0, 0)).count(), 3);
+    assert_eq!(grid.iter_all_neighbours((0, 0, 0)).count(), 7);
+}
+
+#[test]
+fn sparse_grid3() {
+    // Two adjacent cubes expose 10 faces between them
+    let droplet: SparseGrid3<()> =
+        [((1, 1, 1).into(), ()), ((2, 1, 1).into(), ())]
+            .into_iter()
+            .collect();
+    let exposed: usize = droplet
+        .iter()
+        .map(|(at, _)| 6 - droplet.iter_face_neighbours(at).count())
+        .sum();
+    assert_eq!(exposed, 10);
+    assert_eq!(droplet.bounds(), Some(((1, 1, 1).into(), (2, 1, 1).into())));
+
+    let mut grid = SparseGrid3::new();
+    grid.set((-5, 0, 9), 'a');
+    assert_eq!(grid.get((-5, 0, 9)), Some(&'a'));
+    assert_eq!(grid.find(|cell| *cell == 'a'), Some((-5, 0, 9).into()));
+    assert_eq!(grid.iter_all_neighbours((-4, 1, 8)).count(), 1);
+    assert_eq!(grid.remove((-5, 0, 9)), Some('a'));
+    assert!(grid.is_empty());
+}
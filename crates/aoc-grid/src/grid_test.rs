@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashSet;
 
 #[test]
 fn make_grid() {
@@ -93,3 +94,323 @@ fn grid_iter_cardinals() {
     let neigh = grid.iter_cardinal_neighbours((0, 0)).collect::<Vec<_>>();
     assert_eq!(neigh, [((0, 1).into(), &1), ((1, 0).into(), &4)]);
 }
+
+#[test]
+fn grid_iter_all_neighbours() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ], 3);
+
+    let neigh = grid
+        .iter_all_neighbours((1, 1))
+        .map(|(_, val)| *val)
+        .collect::<Vec<_>>();
+    assert_eq!(neigh, [2, 3, 6, 9, 8, 7, 4, 1]);
+
+    let neigh = grid.iter_all_neighbours((0, 0)).collect::<Vec<_>>();
+    assert_eq!(
+        neigh,
+        [
+            ((0, 1).into(), &4),
+            ((1, 1).into(), &5),
+            ((1, 0).into(), &8)
+        ]
+    );
+}
+
+#[test]
+fn grid_iter_neighbours_kinds() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ], 3);
+
+    let values = |kind: &Neighbourhood| {
+        grid.iter_neighbours((1, 1), kind)
+            .map(|(_, val)| *val)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(&Neighbourhood::VonNeumann), [2, 4, 8, 6]);
+    assert_eq!(values(&Neighbourhood::Moore).len(), 8);
+
+    let knight = Neighbourhood::Custom(vec![(1, 2).into(), (-1, -1).into()]);
+    assert_eq!(values(&knight), [7]);
+}
+
+#[test]
+fn grid_rows_and_cols() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2, 3,
+        4, 5, 6,
+    ], 3);
+
+    assert_eq!(grid.row(1).unwrap(), [1, 2, 3]);
+    assert_eq!(grid.row(0).unwrap(), [4, 5, 6]);
+    assert!(grid.row(2).is_none());
+    assert!(grid.row(-1).is_none());
+
+    assert_eq!(grid.col(1).unwrap().collect::<Vec<_>>(), [&2, &5]);
+    assert!(grid.col(3).is_none());
+
+    let cols = grid
+        .cols()
+        .map(|col| col.copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(cols, [[1, 4], [2, 5], [3, 6]]);
+}
+
+#[test]
+fn grid_ray() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ], 3);
+
+    let values = |at: (i64, i64), step: Vector| {
+        grid.ray(at, step).map(|(_, val)| *val).collect::<Vec<_>>()
+    };
+    assert_eq!(values((0, 1), Direction::Right.into()), [5, 6]);
+    assert_eq!(values((1, 0), Direction::Up.into()), [5, 2]);
+    assert_eq!(values((0, 0), Direction8::UpRight.into()), [5, 3]);
+    assert_eq!(values((2, 2), (-2, -1).into()), [4]);
+    assert!(values((2, 2), Direction::Right.into()).is_empty());
+
+    let (last, _) = grid.ray((0, 2), Direction::Down).last().unwrap();
+    assert_eq!(last, Coord::from((0, 0)));
+}
+
+#[test]
+fn grid_get_mut_and_index() {
+    #[rustfmt::skip]
+    let mut grid = Grid::<u16>::new([
+        1, 2,
+        3, 4,
+    ], 2);
+
+    *grid.get_mut((1, 1)).unwrap() += 10;
+    assert_eq!(grid[(1, 1)], 12);
+    assert!(grid.get_mut((2, 0)).is_none());
+
+    grid[Coord::from((0, 0))] = 30;
+    assert_eq!(grid.get((0, 0)), Some(&30));
+}
+
+#[test]
+#[should_panic(expected = "Coordinate (0, 2) out of bounds in a grid of \
+    dimension (2, 2)")]
+fn grid_index_out_of_bounds() {
+    let grid = Grid::<u16>::new([1, 2, 3, 4], 2);
+    let _ = grid[(0, 2)];
+}
+
+#[test]
+fn grid_iter_mut() {
+    let mut grid = Grid::<i64>::new_default(3, 3);
+    for (at, cell) in grid.iter_mut() {
+        *cell = at.x * 10 + at.y;
+    }
+    for (at, cell) in grid.iter() {
+        assert_eq!(*cell, at.x * 10 + at.y);
+    }
+}
+
+#[test]
+fn grid_from_fn_and_map() {
+    let grid = Grid::from_fn(3, 3, |at| at.x + at.y);
+    assert_eq!(grid.to_string(), "234\n123\n012\n");
+
+    let even = grid.map(|cell| cell % 2 == 0);
+    assert_eq!(even.get((1, 1)), Some(&true));
+    assert_eq!(even.get((1, 0)), Some(&false));
+
+    let chars = even.into_map(|even| if even { '#' } else { '.' });
+    assert_eq!(chars.to_string(), "#.#\n.#.\n#.#\n");
+}
+
+#[test]
+fn grid_resize_and_pad() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2,
+        3, 4,
+    ], 2);
+
+    let bigger = grid.resize(3, 3, 0);
+    assert_eq!(bigger.to_string(), "000\n120\n340\n");
+    assert_eq!(bigger.get((0, 0)), grid.get((0, 0)));
+    assert_eq!(bigger.resize(2, 2, 9), grid);
+
+    let padded = grid.pad(1, 0);
+    assert_eq!(padded.to_string(), "0000\n0120\n0340\n0000\n");
+    assert_eq!(padded.get((1, 1)), grid.get((0, 0)));
+}
+
+/// Every shape up to 6x6 in both orientations, each cell holding its
+/// position in storage order
+fn all_shapes() -> impl Iterator<Item = Grid<usize>> {
+    (1..=6).flat_map(|width| {
+        (1..=6).flat_map(move |height| {
+            [Orientation::YUp, Orientation::YDown].map(move |orientation| {
+                let len = usize::try_from(width * height).unwrap();
+                Grid::new((0..len).collect::<Vec<_>>(), width)
+                    .with_orientation(orientation)
+            })
+        })
+    })
+}
+
+#[test]
+fn grid_indexing_agrees() {
+    for grid in all_shapes() {
+        let width = usize::try_from(grid.width()).unwrap();
+        let shape = (grid.width(), grid.height(), grid.orientation());
+        let mut seen = HashSet::new();
+
+        for (idx, (at, &cell)) in grid.iter().enumerate() {
+            assert_eq!(cell, idx, "{shape:?}");
+            assert!(seen.insert(at), "{shape:?} repeats {at}");
+            assert_eq!(grid.idx_to_coord(idx), at, "{shape:?}");
+            assert_eq!(grid.get(at), Some(&cell), "{shape:?} at {at}");
+            assert_eq!(grid[at], cell, "{shape:?} at {at}");
+            assert_eq!(grid.find(|&c| c == cell), Some(at), "{shape:?}");
+            let row = grid.row(at.y).unwrap();
+            assert_eq!(row[usize::try_from(at.x).unwrap()], cell);
+            assert_eq!(grid.rows().nth(idx / width).unwrap(), row);
+        }
+        assert_eq!(seen.len(), grid.iter().count());
+
+        for at in [(-1, 0), (grid.width(), 0), (0, -1), (0, grid.height())] {
+            assert_eq!(grid.get(at), None, "{shape:?} at {at:?}");
+        }
+    }
+}
+
+#[test]
+fn grid_mutation_agrees() {
+    for grid in all_shapes() {
+        let shape = (grid.width(), grid.height(), grid.orientation());
+        let coords = Grid::from_fn_oriented(
+            grid.width(),
+            grid.height(),
+            grid.orientation(),
+            |at| at,
+        );
+        let mut via_set = grid.map(|_| usize::MAX);
+        let mut via_get_mut = via_set.clone();
+        let mut via_index = via_set.clone();
+        let mut via_iter_mut = via_set.clone();
+
+        for ((at, &cell), (from_fn_at, &from_fn_cell)) in
+            grid.iter().zip(coords.iter())
+        {
+            assert_eq!(at, from_fn_at, "{shape:?}");
+            assert_eq!(at, from_fn_cell, "{shape:?}");
+            via_set.set(at, cell);
+            *via_get_mut.get_mut(at).unwrap() = cell;
+            via_index[at] = cell;
+        }
+        for (at, cell) in via_iter_mut.iter_mut() {
+            *cell = grid[at];
+        }
+
+        assert_eq!(via_set, grid, "{shape:?}");
+        assert_eq!(via_get_mut, grid, "{shape:?}");
+        assert_eq!(via_index, grid, "{shape:?}");
+        assert_eq!(via_iter_mut, grid, "{shape:?}");
+    }
+}
+
+#[test]
+fn grid_directions_follow_the_picture() {
+    for grid in all_shapes() {
+        let shape = (grid.width(), grid.height(), grid.orientation());
+        let width = usize::try_from(grid.width()).unwrap();
+        let len = grid.iter().count();
+
+        for (at, &cell) in grid.iter() {
+            let (row, col) = (cell / width, cell % width);
+            let expected = [
+                (Direction::Up, (row > 0).then(|| cell - width)),
+                (Direction::Down, (cell + width < len).then(|| cell + width)),
+                (Direction::Left, (col > 0).then(|| cell - 1)),
+                (Direction::Right, (col + 1 < width).then(|| cell + 1)),
+            ];
+            for (dir, expected) in expected {
+                let found = grid.get_neighbour(at, dir).map(|(_, &c)| c);
+                assert_eq!(found, expected, "{shape:?} {dir:?} of {at}");
+            }
+        }
+    }
+}
+
+#[test]
+fn grid_orientation_origin() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        'a', 'b', 'c',
+        'd', 'e', 'f',
+    ], 3);
+    assert_eq!(grid.get((0, 0)), Some(&'d'));
+    assert_eq!(grid.get((2, 1)), Some(&'c'));
+
+    let screen = grid.clone().with_orientation(Orientation::YDown);
+    assert_eq!(screen.get((0, 0)), Some(&'a'));
+    assert_eq!(screen.get((2, 1)), Some(&'f'));
+    assert_eq!(screen.row(0).unwrap(), ['a', 'b', 'c']);
+    assert_eq!(screen.to_string(), grid.to_string());
+    assert_eq!(
+        screen.get_neighbour((1, 1), Direction::Up),
+        Some(((1, 0).into(), &'b'))
+    );
+    assert_eq!(
+        screen.ray((0, 1), Direction::Up).collect::<Vec<_>>(),
+        [((0, 0).into(), &'a')]
+    );
+    // Directions and the built-in neighbourhoods follow the page, while raw
+    // vectors are in the grid's own coordinates
+    assert_eq!(
+        screen
+            .iter_neighbours((2, 1), &Neighbourhood::VonNeumann)
+            .next(),
+        Some(((2, 0).into(), &'c'))
+    );
+    let custom = Neighbourhood::Custom(vec![(0, 1).into()]);
+    assert_eq!(screen.iter_neighbours((2, 1), &custom).count(), 0);
+    assert_eq!(
+        screen.iter_neighbours((2, 0), &custom).collect::<Vec<_>>(),
+        [((2, 1).into(), &'f')]
+    );
+}
+
+#[test]
+fn grid_ray_y_down() {
+    #[rustfmt::skip]
+    let grid = Grid::<u16>::new([
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ], 3).with_orientation(Orientation::YDown);
+
+    let a = Coord::from((0, 0));
+    let b = Coord::from((1, 1));
+    let (first, _) = grid.ray(a, b - a).next().unwrap();
+    assert_eq!(first, b);
+    assert_eq!(
+        grid.ray(a, b - a).map(|(_, val)| *val).collect::<Vec<_>>(),
+        [5, 9]
+    );
+    assert_eq!(
+        grid.ray((1, 2), Direction::Up)
+            .map(|(_, val)| *val)
+            .collect::<Vec<_>>(),
+        [5, 2]
+    );
+}
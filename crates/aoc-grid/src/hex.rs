@@ -0,0 +1,357 @@
+use crate::{flood_fill, CellularSpace, Space};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter},
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+#[cfg(test)]
+#[path = "hex_test.rs"]
+mod hex_test;
+
+/// Axial hex coordinate. The implied third cube coordinate is `s = -q - r`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexCoord {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl From<(i64, i64)> for HexCoord {
+    fn from((q, r): (i64, i64)) -> Self {
+        Self { q, r }
+    }
+}
+
+impl Display for HexCoord {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        write!(fmt, "({}, {})", self.q, self.r)
+    }
+}
+
+impl HexCoord {
+    const OFFSETS: [(i64, i64); 6] =
+        [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+    pub fn s(self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// Number of steps between two hexes
+    pub fn distance(self, other: Self) -> i64 {
+        let diff = self - other;
+        (diff.q.abs() + diff.r.abs() + diff.s().abs()) / 2
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Self::OFFSETS
+            .into_iter()
+            .map(move |offset| self + Self::from(offset))
+    }
+
+    pub fn step(self, direction: impl Into<HexCoord>) -> Self {
+        self + direction.into()
+    }
+}
+
+impl Add for HexCoord {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            q: self.q + other.q,
+            r: self.r + other.r,
+        }
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self {
+            q: self.q - other.q,
+            r: self.r - other.r,
+        }
+    }
+}
+
+/// Error from `parse_hex_steps`, giving the byte offset of the first step
+/// that isn't a direction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseHexError {
+    pub position: usize,
+}
+
+impl Display for ParseHexError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        write!(fmt, "Invalid hex direction at byte {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseHexError {}
+
+/// Error from parsing a single hex direction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseHexDirectionError;
+
+impl Display for ParseHexDirectionError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        write!(fmt, "Invalid hex direction")
+    }
+}
+
+impl std::error::Error for ParseHexDirectionError {}
+
+/// Directions between hexes with flat tops, written `n`, `ne`, `se`, `s`,
+/// `sw` and `nw`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlatHexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+/// Directions between hexes with pointy tops, written `e`, `se`, `sw`, `w`,
+/// `nw` and `ne`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointyHexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl From<FlatHexDirection> for HexCoord {
+    fn from(dir: FlatHexDirection) -> Self {
+        match dir {
+            FlatHexDirection::North => (0, -1),
+            FlatHexDirection::NorthEast => (1, -1),
+            FlatHexDirection::SouthEast => (1, 0),
+            FlatHexDirection::South => (0, 1),
+            FlatHexDirection::SouthWest => (-1, 1),
+            FlatHexDirection::NorthWest => (-1, 0),
+        }
+        .into()
+    }
+}
+
+impl From<PointyHexDirection> for HexCoord {
+    fn from(dir: PointyHexDirection) -> Self {
+        match dir {
+            PointyHexDirection::East => (1, 0),
+            PointyHexDirection::SouthEast => (0, 1),
+            PointyHexDirection::SouthWest => (-1, 1),
+            PointyHexDirection::West => (-1, 0),
+            PointyHexDirection::NorthWest => (0, -1),
+            PointyHexDirection::NorthEast => (1, -1),
+        }
+        .into()
+    }
+}
+
+impl FromStr for FlatHexDirection {
+    type Err = ParseHexDirectionError;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        Ok(match inp {
+            "n" => Self::North,
+            "ne" => Self::NorthEast,
+            "se" => Self::SouthEast,
+            "s" => Self::South,
+            "sw" => Self::SouthWest,
+            "nw" => Self::NorthWest,
+            _ => return Err(ParseHexDirectionError),
+        })
+    }
+}
+
+impl FromStr for PointyHexDirection {
+    type Err = ParseHexDirectionError;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        Ok(match inp {
+            "e" => Self::East,
+            "se" => Self::SouthEast,
+            "sw" => Self::SouthWest,
+            "w" => Self::West,
+            "nw" => Self::NorthWest,
+            "ne" => Self::NorthEast,
+            _ => return Err(ParseHexDirectionError),
+        })
+    }
+}
+
+/// Split a string of steps into directions. Steps may be separated by commas
+/// or whitespace (`ne,ne,s`) or run together (`esenee`).
+pub fn parse_hex_steps<D>(inp: &str) -> Result<Vec<D>, ParseHexError>
+where
+    D: FromStr,
+{
+    let mut steps = Vec::new();
+    let mut position = 0;
+    while position < inp.len() {
+        let rest = &inp[position..];
+        if rest.starts_with([',', ' ', '\t', '\n', '\r']) {
+            position += 1;
+            continue;
+        }
+        let (len, step) = [2, 1]
+            .into_iter()
+            .filter_map(|len| rest.get(..len).map(|token| (len, token)))
+            .find_map(|(len, token)| token.parse().ok().map(|step| (len, step)))
+            .ok_or(ParseHexError { position })?;
+        steps.push(step);
+        position += len;
+    }
+    Ok(steps)
+}
+
+/// Unbounded grid of hexes which only stores the cells that have been
+/// written. When used as a cellular space, unset cells take the default value
+/// and cells equal to the default are not kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexGrid<T> {
+    inner: HashMap<HexCoord, T>,
+}
+
+impl<T> Default for HexGrid<T> {
+    fn default() -> Self {
+        Self {
+            inner: HashMap::new(),
+        }
+    }
+}
+
+impl<T> HexGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn get(&self, at: impl Into<HexCoord>) -> Option<&T> {
+        self.inner.get(&at.into())
+    }
+
+    pub fn get_mut(&mut self, at: impl Into<HexCoord>) -> Option<&mut T> {
+        self.inner.get_mut(&at.into())
+    }
+
+    pub fn set(&mut self, at: impl Into<HexCoord>, new: T) {
+        self.inner.insert(at.into(), new);
+    }
+
+    pub fn remove(&mut self, at: impl Into<HexCoord>) -> Option<T> {
+        self.inner.remove(&at.into())
+    }
+
+    pub fn find<F>(&self, mut condition: F) -> Option<HexCoord>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner
+            .iter()
+            .find(|(_, cell)| condition(cell))
+            .map(|(at, _)| *at)
+    }
+
+    /// Occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (HexCoord, &T)> {
+        self.inner.iter().map(|(at, value)| (*at, value))
+    }
+
+    pub fn iter_neighbours(
+        &self,
+        at: impl Into<HexCoord>,
+    ) -> impl Iterator<Item = (HexCoord, &T)> {
+        at.into()
+            .neighbours()
+            .filter_map(|at| self.get(at).map(|val| (at, val)))
+    }
+
+    /// All occupied cells reachable from `start` through cells satisfying
+    /// `predicate`
+    pub fn flood_fill<P>(
+        &self,
+        start: impl Into<HexCoord>,
+        predicate: P,
+    ) -> HashSet<HexCoord>
+    where
+        P: FnMut((HexCoord, &T)) -> bool,
+    {
+        flood_fill(self, start.into(), predicate)
+    }
+}
+
+impl<T> FromIterator<(HexCoord, T)> for HexGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (HexCoord, T)>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Space for HexGrid<T> {
+    type Coord = HexCoord;
+    type Cell = T;
+
+    fn cell(&self, at: HexCoord) -> Option<&T> {
+        self.get(at)
+    }
+
+    fn adjacent(&self, at: HexCoord) -> impl Iterator<Item = HexCoord> {
+        at.neighbours()
+    }
+}
+
+/// Every hex has the same six neighbours, so there is nothing to choose
+impl<T: Clone + PartialEq + Default> CellularSpace for HexGrid<T> {
+    type Neighbours = ();
+
+    fn collect_neighbours<'a>(
+        &'a self,
+        at: HexCoord,
+        _kind: &(),
+        buf: &mut Vec<&'a T>,
+    ) {
+        buf.clear();
+        buf.extend(self.iter_neighbours(at).map(|(_, val)| val));
+    }
+
+    fn step_into<F>(&self, next: &mut Self, mut update: F) -> usize
+    where
+        F: FnMut(HexCoord, &T) -> T,
+    {
+        let background = T::default();
+        let candidates = self
+            .inner
+            .keys()
+            .flat_map(|at| at.neighbours().chain([*at]))
+            .collect::<HashSet<_>>();
+
+        let mut changed = 0;
+        next.inner.clear();
+        for at in candidates {
+            let cell = self.get(at).unwrap_or(&background);
+            let new = update(at, cell);
+            if new != *cell {
+                changed += 1;
+            }
+            if new != background {
+                next.inner.insert(at, new);
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,133 @@
+use super::*;
+use crate::{Automaton, Settled};
+
+fn walk<D>(steps: &str) -> HexCoord
+where
+    D: FromStr + Into<HexCoord>,
+{
+    parse_hex_steps::<D>(steps)
+        .unwrap()
+        .into_iter()
+        .fold(HexCoord::default(), HexCoord::step)
+}
+
+#[test]
+fn flat_distance() {
+    let origin = HexCoord::default();
+    let cases = [
+        ("ne,ne,ne", 3),
+        ("ne,ne,sw,sw", 0),
+        ("ne,ne,s,s", 2),
+        ("se,sw,se,sw,sw", 3),
+    ];
+    for (steps, distance) in cases {
+        assert_eq!(
+            walk::<FlatHexDirection>(steps).distance(origin),
+            distance,
+            "{steps}"
+        );
+    }
+}
+
+#[test]
+fn pointy_steps() {
+    assert_eq!(walk::<PointyHexDirection>("nwwswee"), HexCoord::default());
+    assert_eq!(
+        walk::<PointyHexDirection>("esew"),
+        HexCoord::from(PointyHexDirection::SouthEast)
+    );
+    assert_eq!(
+        parse_hex_steps::<PointyHexDirection>("sesenwnw").unwrap(),
+        [
+            PointyHexDirection::SouthEast,
+            PointyHexDirection::SouthEast,
+            PointyHexDirection::NorthWest,
+            PointyHexDirection::NorthWest,
+        ]
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        parse_hex_steps::<FlatHexDirection>("n,e"),
+        Err(ParseHexError { position: 2 })
+    );
+    assert_eq!(
+        parse_hex_steps::<PointyHexDirection>("eex"),
+        Err(ParseHexError { position: 2 })
+    );
+    assert_eq!(parse_hex_steps::<FlatHexDirection>("").unwrap(), []);
+
+    assert_eq!("e".parse::<FlatHexDirection>(), Err(ParseHexDirectionError));
+    assert_eq!(
+        "n".parse::<PointyHexDirection>(),
+        Err(ParseHexDirectionError)
+    );
+    assert_eq!("nw".parse(), Ok(PointyHexDirection::NorthWest));
+}
+
+#[test]
+fn hex_neighbours() {
+    let centre = HexCoord::from((2, -1));
+    let neigh = centre.neighbours().collect::<HashSet<_>>();
+    assert_eq!(neigh.len(), 6);
+    assert!(neigh.iter().all(|at| at.distance(centre) == 1));
+    assert_eq!(centre.s(), -1);
+
+    let mut grid = HexGrid::new();
+    grid.set(centre, 'a');
+    grid.set(centre.step(FlatHexDirection::North), 'b');
+    grid.set((10, 10), 'c');
+    assert_eq!(grid.iter_neighbours(centre).count(), 1);
+    assert_eq!(grid.find(|cell| *cell == 'c'), Some((10, 10).into()));
+}
+
+#[test]
+fn hex_flood_fill() {
+    let line = (0..4).map(|q| (HexCoord::from((q, 0)), q));
+    let grid = line
+        .chain([(HexCoord::from((0, 5)), 9)])
+        .collect::<HexGrid<_>>();
+    assert_eq!(grid.flood_fill((0, 0), |_| true).len(), 4);
+    assert_eq!(grid.flood_fill((0, 0), |(_, cell)| *cell < 2).len(), 2);
+    assert_eq!(grid.flood_fill((0, 5), |_| true).len(), 1);
+    assert!(grid.flood_fill((0, 1), |_| true).is_empty());
+}
+
+#[test]
+fn hex_life() {
+    // Black tiles flip to white with 0 or more than 2 black neighbours, and
+    // white tiles flip to black with exactly 2
+    let rule = |black: &bool, neighbours: &[&bool]| {
+        let count = neighbours.iter().filter(|cell| ***cell).count();
+        if *black {
+            count == 1 || count == 2
+        } else {
+            count == 2
+        }
+    };
+
+    let mut lonely = Automaton::new(
+        [(HexCoord::default(), true)]
+            .into_iter()
+            .collect::<HexGrid<_>>(),
+        (),
+    );
+    assert_eq!(lonely.step(rule), 1);
+    assert!(lonely.grid().is_empty());
+    assert_eq!(
+        lonely.run_until_stable(rule),
+        Settled::FixedPoint { generation: 1 }
+    );
+
+    let pair = [(0, 0), (1, 0)]
+        .into_iter()
+        .map(|at| (HexCoord::from(at), true))
+        .collect::<HexGrid<_>>();
+    let mut automaton = Automaton::new(pair, ());
+    assert_eq!(automaton.step(rule), 2);
+    assert_eq!(automaton.grid().len(), 4);
+    assert_eq!(automaton.grid().get((1, -1)), Some(&true));
+    assert_eq!(automaton.grid().get((0, 1)), Some(&true));
+}
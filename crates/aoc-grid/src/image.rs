@@ -0,0 +1,253 @@
+use crate::Grid;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+#[cfg(test)]
+#[path = "image_test.rs"]
+mod image_test;
+
+/// GIF images are limited to 16 bit dimensions and 256 colours per frame
+const GIF_MAX_COLOURS: usize = 256;
+/// Emit a clear code often enough that the decoder's dictionary never needs
+/// codes wider than 9 bits, so every pixel can be written as a literal
+const GIF_CLEAR_INTERVAL: usize = 254;
+
+impl<T> Grid<T> {
+    /// Write a binary (P6) PPM image with one pixel per cell, top row first
+    pub fn write_ppm<W, C>(&self, mut writer: W, colour: C) -> io::Result<()>
+    where
+        W: Write,
+        C: Fn(&T) -> [u8; 3],
+    {
+        write!(writer, "P6\n{} {}\n255\n", self.width(), self.height())?;
+        for cell in self.rows().flatten() {
+            writer.write_all(&colour(cell))?;
+        }
+        writer.flush()
+    }
+
+    /// Write a binary (P5) greyscale PGM image with one pixel per cell
+    pub fn write_pgm<W, S>(&self, mut writer: W, shade: S) -> io::Result<()>
+    where
+        W: Write,
+        S: Fn(&T) -> u8,
+    {
+        write!(writer, "P5\n{} {}\n255\n", self.width(), self.height())?;
+        let pixels = self.rows().flatten().map(shade).collect::<Vec<_>>();
+        writer.write_all(&pixels)?;
+        writer.flush()
+    }
+}
+
+/// Streams grids into an animated GIF. The image data is stored without
+/// compression, so no encoder library is needed.
+pub struct GifEncoder<W: Write> {
+    writer: W,
+    width: u16,
+    height: u16,
+    /// Frame delay in hundredths of a second
+    delay: u16,
+}
+
+impl<W: Write> GifEncoder<W> {
+    pub fn new(
+        mut writer: W,
+        width: i64,
+        height: i64,
+        delay: u16,
+    ) -> io::Result<Self> {
+        let dimension = |dim: i64| {
+            u16::try_from(dim).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("GIF dimension {dim} out of range"),
+                )
+            })
+        };
+        let width = dimension(width)?;
+        let height = dimension(height)?;
+
+        writer.write_all(b"GIF89a")?;
+        writer.write_all(&width.to_le_bytes())?;
+        writer.write_all(&height.to_le_bytes())?;
+        // No global colour table, background colour 0, square pixels
+        writer.write_all(&[0, 0, 0])?;
+        // Application extension asking viewers to loop forever
+        writer.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+        Ok(Self {
+            writer,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn add_frame<T, C>(
+        &mut self,
+        grid: &Grid<T>,
+        colour: C,
+    ) -> io::Result<()>
+    where
+        C: Fn(&T) -> [u8; 3],
+    {
+        if (grid.width(), grid.height())
+            != (i64::from(self.width), i64::from(self.height))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Frame dimensions differ from the animation",
+            ));
+        }
+
+        let mut palette = HashMap::new();
+        let mut pixels = Vec::with_capacity(
+            usize::from(self.width) * usize::from(self.height),
+        );
+        for cell in grid.rows().flatten() {
+            let rgb = colour(cell);
+            let next = palette.len();
+            let idx = *palette.entry(rgb).or_insert(next);
+            if idx >= GIF_MAX_COLOURS {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "GIF frames are limited to 256 colours",
+                ));
+            }
+            pixels.push(idx as u8);
+        }
+        let mut colour_table = [0; GIF_MAX_COLOURS * 3];
+        for (rgb, idx) in palette {
+            colour_table[idx * 3..idx * 3 + 3].copy_from_slice(&rgb);
+        }
+
+        // Graphic control extension carrying the frame delay
+        self.writer.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        self.writer.write_all(&self.delay.to_le_bytes())?;
+        self.writer.write_all(&[0x00, 0x00])?;
+
+        // Image descriptor covering the whole canvas, with a local colour
+        // table of 256 entries
+        self.writer.write_all(&[0x2c, 0, 0, 0, 0])?;
+        self.writer.write_all(&self.width.to_le_bytes())?;
+        self.writer.write_all(&self.height.to_le_bytes())?;
+        self.writer.write_all(&[0x87])?;
+        self.writer.write_all(&colour_table)?;
+
+        self.writer.write_all(&[8])?;
+        for block in uncompressed_lzw(&pixels).chunks(255) {
+            self.writer.write_all(&[block.len() as u8])?;
+            self.writer.write_all(block)?;
+        }
+        self.writer.write_all(&[0])
+    }
+
+    /// Write the trailer and hand back the underlying writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&[0x3b])?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Encode 8-bit pixels as a stream of 9-bit literal LZW codes
+fn uncompressed_lzw(pixels: &[u8]) -> Vec<u8> {
+    const CLEAR: u16 = 256;
+    const END: u16 = 257;
+
+    let mut out = Vec::with_capacity(pixels.len() * 9 / 8 + 4);
+    let mut acc = 0_u32;
+    let mut bits = 0;
+    let mut push = |code: u16| {
+        acc |= u32::from(code) << bits;
+        bits += 9;
+        while bits >= 8 {
+            out.push(acc as u8);
+            acc >>= 8;
+            bits -= 8;
+        }
+    };
+
+    for chunk in pixels.chunks(GIF_CLEAR_INTERVAL) {
+        push(CLEAR);
+        for &pixel in chunk {
+            push(u16::from(pixel));
+        }
+    }
+    push(END);
+    if bits > 0 {
+        out.push(acc as u8);
+    }
+    out
+}
+
+enum Output {
+    PpmSequence { dir: PathBuf, next: usize },
+    Gif(GifEncoder<BufWriter<File>>),
+}
+
+/// Records the successive states of a simulation to disk
+pub struct FrameRecorder {
+    output: Output,
+    frames: usize,
+}
+
+impl FrameRecorder {
+    /// Write each frame as `frame_00000.ppm`, `frame_00001.ppm`, ... inside
+    /// `dir`, creating it if necessary
+    pub fn ppm_sequence(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self {
+            output: Output::PpmSequence { dir, next: 0 },
+            frames: 0,
+        })
+    }
+
+    /// Write all frames into a single animated GIF. Every frame must have
+    /// the given dimensions. `delay` is in hundredths of a second.
+    pub fn gif(
+        path: impl AsRef<Path>,
+        width: i64,
+        height: i64,
+        delay: u16,
+    ) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(Self {
+            output: Output::Gif(GifEncoder::new(file, width, height, delay)?),
+            frames: 0,
+        })
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn record<T, C>(&mut self, grid: &Grid<T>, colour: C) -> io::Result<()>
+    where
+        C: Fn(&T) -> [u8; 3],
+    {
+        match &mut self.output {
+            Output::PpmSequence { dir, next } => {
+                let path = dir.join(format!("frame_{next:05}.ppm"));
+                grid.write_ppm(BufWriter::new(File::create(path)?), colour)?;
+                *next += 1;
+            }
+            Output::Gif(encoder) => encoder.add_frame(grid, colour)?,
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Flush any buffered output. Required for GIFs to be readable.
+    pub fn finish(self) -> io::Result<()> {
+        match self.output {
+            Output::PpmSequence { .. } => Ok(()),
+            Output::Gif(encoder) => encoder.finish().map(drop),
+        }
+    }
+}
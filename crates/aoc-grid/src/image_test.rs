@@ -0,0 +1,203 @@
+use super::*;
+
+#[rustfmt::skip]
+fn sample() -> Grid<u8> {
+    Grid::new([
+        0, 1, 2,
+        3, 4, 5,
+    ], 3)
+}
+
+fn rgb(cell: &u8) -> [u8; 3] {
+    [*cell, cell.wrapping_mul(10), 255 - cell]
+}
+
+#[test]
+fn ppm_bytes() {
+    let mut out = Vec::new();
+    sample().write_ppm(&mut out, rgb).unwrap();
+    let (header, pixels) = out.split_at(11);
+    assert_eq!(header, b"P6\n3 2\n255\n");
+    assert_eq!(pixels.len(), 18);
+    assert_eq!(&pixels[..6], [0, 0, 255, 1, 10, 254]);
+    assert_eq!(&pixels[15..], [5, 50, 250]);
+}
+
+#[test]
+fn pgm_bytes() {
+    let mut out = Vec::new();
+    sample().write_pgm(&mut out, |cell| cell * 50).unwrap();
+    assert_eq!(out, b"P5\n3 2\n255\n\x00\x32\x64\x96\xc8\xfa");
+}
+
+/// Minimal GIF reader, just enough to check what `GifEncoder` produces
+fn decode_gif(data: &[u8]) -> Vec<Vec<[u8; 3]>> {
+    assert_eq!(&data[..6], b"GIF89a");
+    let mut pos = 13;
+    let mut frames = Vec::new();
+    loop {
+        match data[pos] {
+            0x3b => return frames,
+            0x21 => {
+                pos += 2;
+                while data[pos] != 0 {
+                    pos += usize::from(data[pos]) + 1;
+                }
+                pos += 1;
+            }
+            0x2c => {
+                assert_eq!(data[pos + 9], 0x87);
+                let table = &data[pos + 10..pos + 10 + 768];
+                pos += 10 + 768;
+                assert_eq!(data[pos], 8);
+                pos += 1;
+                let mut lzw = Vec::new();
+                while data[pos] != 0 {
+                    let len = usize::from(data[pos]);
+                    lzw.extend_from_slice(&data[pos + 1..pos + 1 + len]);
+                    pos += len + 1;
+                }
+                pos += 1;
+                frames.push(
+                    decode_lzw(&lzw)
+                        .into_iter()
+                        .map(|idx| {
+                            let idx = usize::from(idx) * 3;
+                            [table[idx], table[idx + 1], table[idx + 2]]
+                        })
+                        .collect(),
+                );
+            }
+            other => panic!("Unexpected block {other:#x}"),
+        }
+    }
+}
+
+fn decode_lzw(data: &[u8]) -> Vec<u8> {
+    let mut width = 9;
+    let mut dict: Vec<Vec<u8>> = Vec::new();
+    let reset = |dict: &mut Vec<Vec<u8>>| {
+        *dict = (0..=255).map(|idx| vec![idx]).collect();
+        dict.extend([vec![], vec![]]);
+    };
+    reset(&mut dict);
+
+    let mut out = Vec::new();
+    let mut prev: Option<Vec<u8>> = None;
+    let mut bit = 0;
+    loop {
+        let mut code = 0_usize;
+        for offset in 0..width {
+            let idx = bit + offset;
+            code |= usize::from((data[idx / 8] >> (idx % 8)) & 1) << offset;
+        }
+        bit += width;
+
+        match code {
+            256 => {
+                reset(&mut dict);
+                width = 9;
+                prev = None;
+                continue;
+            }
+            257 => return out,
+            _ => {}
+        }
+        let entry = if code < dict.len() {
+            dict[code].clone()
+        } else {
+            let prev = prev.clone().unwrap();
+            [prev.clone(), vec![prev[0]]].concat()
+        };
+        if let Some(prev) = prev {
+            dict.push([prev, vec![entry[0]]].concat());
+            if dict.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+        }
+        out.extend_from_slice(&entry);
+        prev = Some(entry);
+    }
+}
+
+#[test]
+fn gif_round_trip() {
+    // Large enough to need several clear codes
+    let grid =
+        Grid::new((0..=255).cycle().take(40 * 30).collect::<Vec<u8>>(), 40);
+    let mut encoder = GifEncoder::new(Vec::new(), 40, 30, 5).unwrap();
+    encoder.add_frame(&grid, rgb).unwrap();
+    encoder
+        .add_frame(&Grid::new(vec![7; 40 * 30], 40), rgb)
+        .unwrap();
+    let data = encoder.finish().unwrap();
+
+    let frames = decode_gif(&data);
+    assert_eq!(frames.len(), 2);
+    let expected = grid.rows().flatten().map(rgb).collect::<Vec<_>>();
+    assert_eq!(frames[0], expected);
+    assert!(frames[1].iter().all(|pixel| *pixel == rgb(&7)));
+}
+
+#[test]
+fn gif_rejects_mismatched_frame() {
+    let mut encoder = GifEncoder::new(Vec::new(), 2, 2, 5).unwrap();
+    let err = encoder.add_frame(&sample(), rgb).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn gif_rejects_too_many_colours() {
+    let grid = Grid::new((0..300).collect::<Vec<u16>>(), 30);
+    let mut encoder = GifEncoder::new(Vec::new(), 30, 10, 5).unwrap();
+    encoder.add_frame(&grid, |_| [0, 0, 0]).unwrap();
+    let err = encoder
+        .add_frame(&grid, |cell| {
+            let [lo, hi] = cell.to_le_bytes();
+            [lo, hi, 0]
+        })
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn recorder_ppm_sequence() {
+    let dir = std::env::temp_dir()
+        .join(format!("aoc-grid-frames-{}", std::process::id()));
+    let mut recorder = FrameRecorder::ppm_sequence(&dir).unwrap();
+    let mut grid = sample();
+    for _ in 0..3 {
+        recorder.record(&grid, rgb).unwrap();
+        grid = grid.flip_horizontal();
+    }
+    assert_eq!(recorder.frames(), 3);
+    recorder.finish().unwrap();
+
+    let mut expected = Vec::new();
+    sample()
+        .flip_horizontal()
+        .write_ppm(&mut expected, rgb)
+        .unwrap();
+    assert_eq!(
+        std::fs::read(dir.join("frame_00001.ppm")).unwrap(),
+        expected
+    );
+    assert!(dir.join("frame_00002.ppm").exists());
+    assert!(!dir.join("frame_00003.ppm").exists());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn recorder_gif() {
+    let path = std::env::temp_dir()
+        .join(format!("aoc-grid-frames-{}.gif", std::process::id()));
+    let mut recorder = FrameRecorder::gif(&path, 3, 2, 10).unwrap();
+    recorder.record(&sample(), rgb).unwrap();
+    recorder.record(&sample().flip_vertical(), rgb).unwrap();
+    recorder.finish().unwrap();
+
+    let frames = decode_gif(&std::fs::read(&path).unwrap());
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[1][0], rgb(&3));
+    std::fs::remove_file(path).unwrap();
+}
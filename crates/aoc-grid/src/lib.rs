@@ -1,28 +1,45 @@
+mod automaton;
+mod bitgrid;
 mod coord;
+mod coord3;
+mod direction;
+mod geometry;
 mod grid;
+mod grid3;
+mod hex;
+mod image;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parse;
+mod pathfinding;
+mod region;
+mod render;
+mod space;
+mod sparse;
+mod subgrid;
+mod transform;
+mod wrapping;
 
+pub use automaton::*;
+pub use bitgrid::*;
 pub use coord::*;
+pub use coord3::*;
+pub use direction::*;
+pub use geometry::*;
 pub use grid::*;
+pub use grid3::*;
+pub use hex::*;
+pub use image::*;
+pub use parse::*;
+pub use pathfinding::*;
+pub use region::*;
+pub use render::*;
+pub use space::*;
+pub use sparse::*;
+pub use subgrid::*;
+pub use wrapping::*;
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-impl Direction {
-    pub fn vector(self) -> Vector {
-        match self {
-            Self::Up => (0, 1).into(),
-            Self::Left => (-1, 0).into(),
-            Self::Down => (0, -1).into(),
-            Self::Right => (1, 0).into(),
-        }
-    }
-
-    pub fn iter() -> impl Iterator<Item = Self> {
-        [Self::Up, Self::Left, Self::Down, Self::Right].into_iter()
-    }
-}
+#[cfg(feature = "colour")]
+pub use owo_colors::Style;
+#[cfg(feature = "rayon")]
+pub use rayon;
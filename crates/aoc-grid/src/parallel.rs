@@ -0,0 +1,64 @@
+use crate::{coord::Coord, grid::idx_to_coord, Grid};
+use rayon::prelude::*;
+
+#[cfg(test)]
+#[path = "parallel_test.rs"]
+mod parallel_test;
+
+impl<T: Sync> Grid<T> {
+    /// Parallel version of `iter`, visiting cells in the same order
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = (Coord, &T)> {
+        self.cells()
+            .par_iter()
+            .enumerate()
+            .map(|(idx, value)| (self.idx_to_coord(idx), value))
+    }
+
+    /// Parallel version of `rows`
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]> {
+        let width = self.width().try_into().unwrap();
+        self.cells().par_chunks(width)
+    }
+}
+
+impl<T: Send> Grid<T> {
+    /// Parallel version of `iter_mut`
+    pub fn par_iter_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = (Coord, &mut T)> {
+        let (width, height) = (self.width(), self.height());
+        let orientation = self.orientation();
+        self.cells_mut()
+            .par_iter_mut()
+            .enumerate()
+            .map(move |(idx, value)| {
+                (idx_to_coord(width, height, orientation, idx), value)
+            })
+    }
+}
+
+impl<T: Clone + Send + Sync> Grid<T> {
+    /// Call `f` once per variant on a thread pool. Each call gets a fresh
+    /// copy of this grid to mutate as it likes, so `f` typically alters the
+    /// copy according to the variant and then measures the result.
+    /// Copies are reused between calls on the same thread.
+    pub fn par_variants<I, R, F>(
+        &self,
+        variants: I,
+        f: F,
+    ) -> impl IndexedParallelIterator<Item = R>
+    where
+        I: IntoParallelIterator,
+        I::Iter: IndexedParallelIterator,
+        R: Send,
+        F: Fn(&mut Self, I::Item) -> R + Send + Sync,
+    {
+        variants.into_par_iter().map_init(
+            || self.clone(),
+            move |grid, variant| {
+                grid.clone_from(self);
+                f(grid, variant)
+            },
+        )
+    }
+}
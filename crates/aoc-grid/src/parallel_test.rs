@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn par_iter_matches_iter() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        1, 2, 3,
+        4, 5, 6,
+    ], 3);
+    let serial = grid.iter().collect::<Vec<_>>();
+    let parallel = grid.par_iter().collect::<Vec<_>>();
+    assert_eq!(serial, parallel);
+
+    let rows = grid.par_rows().collect::<Vec<_>>();
+    assert_eq!(rows, grid.rows().collect::<Vec<_>>());
+}
+
+#[test]
+fn par_iter_mut_sees_coordinates() {
+    let mut grid = Grid::<i64>::new_default(4, 3);
+    grid.par_iter_mut()
+        .for_each(|(at, cell)| *cell = at.x * 10 + at.y);
+    for (at, cell) in grid.iter() {
+        assert_eq!(*cell, at.x * 10 + at.y);
+    }
+}
+
+#[test]
+fn par_variants_start_from_the_original() {
+    let grid = Grid::<u8>::new_default(3, 3);
+    let coords = grid.iter().map(|(at, _)| at).collect::<Vec<_>>();
+    let counts = grid
+        .par_variants(coords, |grid, at| {
+            grid.set(at, 1);
+            grid.iter().filter(|(_, cell)| **cell == 1).count()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(counts, [1; 9]);
+
+    let first_blocked = grid
+        .par_variants(0..9, |grid, count| {
+            for (_, cell) in grid.iter_mut().take(count) {
+                *cell = 1;
+            }
+            grid.bfs((0, 0), (2, 2), |(_, cell)| *cell == 0).is_none()
+        })
+        .position_first(|blocked| blocked);
+    // The top row is filled first, and (2, 2) is its last cell
+    assert_eq!(first_blocked, Some(3));
+}
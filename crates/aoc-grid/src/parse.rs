@@ -0,0 +1,210 @@
+use crate::{coord::Coord, Grid, Orientation};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+#[cfg(test)]
+#[path = "parse_test.rs"]
+mod parse_test;
+
+/// Failure to turn puzzle text into a grid. Line and column numbers are
+/// 1-based and count characters as they appear in the text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseGridError<E> {
+    Empty,
+    RaggedLine {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCell {
+        line: usize,
+        column: usize,
+        chr: char,
+        source: E,
+    },
+}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(fmt, "Grid input is empty"),
+            Self::RaggedLine {
+                line,
+                expected,
+                found,
+            } => write!(
+                fmt,
+                "Line {line} has width {found} but expected {expected}"
+            ),
+            Self::InvalidCell {
+                line,
+                column,
+                chr,
+                source,
+            } => write!(
+                fmt,
+                "Invalid cell {chr:?} at line {line} column {column}: {source}"
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidCell { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Positions of marker characters picked out while parsing, in reading order
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markers {
+    inner: HashMap<char, Vec<Coord>>,
+    height: i64,
+    orientation: Orientation,
+}
+
+impl Markers {
+    /// Convert the recorded positions to match a grid that has been given
+    /// the same orientation with `Grid::with_orientation`
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        if orientation != self.orientation {
+            for at in self.inner.values_mut().flatten() {
+                at.y = self.height - 1 - at.y;
+            }
+            self.orientation = orientation;
+        }
+        self
+    }
+
+    pub fn first(&self, marker: char) -> Option<Coord> {
+        self.all(marker).first().copied()
+    }
+
+    pub fn all(&self, marker: char) -> &[Coord] {
+        self.inner.get(&marker).map_or(&[], Vec::as_slice)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn parse<F, E>(
+        text: &str,
+        convert: F,
+    ) -> Result<Self, ParseGridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Self::parse_with_markers(text, &[], convert).map(|(grid, _)| grid)
+    }
+
+    /// As `parse`, also recording where each of the `markers` characters
+    /// appeared. Marker characters are still passed to `convert`.
+    pub fn parse_with_markers<F, E>(
+        text: &str,
+        markers: &[char],
+        convert: F,
+    ) -> Result<(Self, Markers), ParseGridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Self::parse_with_markers_oriented(
+            text,
+            markers,
+            Orientation::YUp,
+            convert,
+        )
+    }
+
+    /// As `parse_with_markers`, building a grid with the given orientation.
+    /// The marker positions are in that grid's coordinates.
+    pub fn parse_with_markers_oriented<F, E>(
+        text: &str,
+        markers: &[char],
+        orientation: Orientation,
+        mut convert: F,
+    ) -> Result<(Self, Markers), ParseGridError<E>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut data = Vec::with_capacity(text.len());
+        let mut width = None;
+        let mut found = Vec::new();
+
+        // Blank lines before the grid are skipped, but still counted in the
+        // line numbers of any error
+        let lines = text
+            .trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .skip_while(|(_, line)| line.is_empty());
+        for (row, (line_idx, line)) in lines.enumerate() {
+            let mut line_width = 0;
+            for (col, chr) in line.chars().enumerate() {
+                let cell = convert(chr).map_err(|source| {
+                    ParseGridError::InvalidCell {
+                        line: line_idx + 1,
+                        column: col + 1,
+                        chr,
+                        source,
+                    }
+                })?;
+                if markers.contains(&chr) {
+                    found.push((chr, row, col));
+                }
+                data.push(cell);
+                line_width += 1;
+            }
+
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(ParseGridError::RaggedLine {
+                        line: line_idx + 1,
+                        expected,
+                        found: line_width,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        let width = match width {
+            Some(0) | None => return Err(ParseGridError::Empty),
+            Some(width) => width,
+        };
+        let grid = Self::new(data, width.try_into().unwrap())
+            .with_orientation(orientation);
+
+        let mut markers = Markers {
+            height: grid.height(),
+            orientation,
+            ..Markers::default()
+        };
+        for (chr, row, col) in found {
+            let row = i64::try_from(row).unwrap();
+            let at =
+                Coord::from((col.try_into().unwrap(), grid.row_index(row)));
+            markers.inner.entry(chr).or_default().push(at);
+        }
+
+        Ok((grid, markers))
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+{
+    type Err = ParseGridError<T::Error>;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        Self::parse(inp, T::try_from)
+    }
+}
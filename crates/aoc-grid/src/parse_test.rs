@@ -0,0 +1,154 @@
+use super::*;
+
+#[test]
+fn parse_chars() {
+    let grid: Grid<char> = "ab\ncd\n".parse().unwrap();
+    assert_eq!(grid.width(), 2);
+    assert_eq!(grid.height(), 2);
+    assert_eq!(*grid.get((0, 1)).unwrap(), 'a');
+    assert_eq!(*grid.get((1, 0)).unwrap(), 'd');
+    assert_eq!(grid.to_string(), "ab\ncd\n");
+}
+
+#[test]
+fn parse_with_closure() {
+    let grid =
+        Grid::parse("12\n34", |chr| chr.to_digit(10).ok_or("not a digit"))
+            .unwrap();
+    assert_eq!(*grid.get((0, 0)).unwrap(), 3);
+
+    let err =
+        Grid::parse("12\n3x", |chr| chr.to_digit(10).ok_or("not a digit"))
+            .unwrap_err();
+    assert_eq!(
+        err,
+        ParseGridError::InvalidCell {
+            line: 2,
+            column: 2,
+            chr: 'x',
+            source: "not a digit",
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Invalid cell 'x' at line 2 column 2: not a digit"
+    );
+}
+
+#[test]
+fn parse_try_from_error() {
+    let err = "12\n3€".parse::<Grid<u8>>().unwrap_err();
+    assert!(matches!(
+        err,
+        ParseGridError::InvalidCell {
+            line: 2,
+            column: 2,
+            chr: '€',
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_ragged() {
+    let err = "abc\nab\nabc".parse::<Grid<char>>().unwrap_err();
+    assert_eq!(
+        err,
+        ParseGridError::RaggedLine {
+            line: 2,
+            expected: 3,
+            found: 2,
+        }
+    );
+}
+
+#[test]
+fn parse_empty() {
+    assert_eq!("".parse::<Grid<char>>().unwrap_err(), ParseGridError::Empty);
+    assert_eq!(
+        "\n\n".parse::<Grid<char>>().unwrap_err(),
+        ParseGridError::Empty
+    );
+}
+
+#[test]
+fn parse_leading_blank_lines() {
+    let grid: Grid<char> = "\n\nab\ncd\n".parse().unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(grid.get((0, 1)), Some(&'a'));
+
+    // Errors still give the line number in the original text
+    let err = "\nabc\nab".parse::<Grid<char>>().unwrap_err();
+    assert_eq!(
+        err,
+        ParseGridError::RaggedLine {
+            line: 3,
+            expected: 3,
+            found: 2,
+        }
+    );
+}
+
+#[test]
+fn parse_markers() {
+    let (grid, markers) = Grid::parse_with_markers(
+        "S..\n.#.\n..E\n..E\n",
+        &['S', 'E', '^'],
+        |chr| Ok::<_, ()>(chr == '#'),
+    )
+    .unwrap();
+    assert_eq!(grid.height(), 4);
+    assert_eq!(markers.first('S'), Some((0, 3).into()));
+    assert_eq!(markers.all('E'), [(2, 1).into(), (2, 0).into()]);
+    assert_eq!(markers.first('^'), None);
+    assert!(markers.all('#').is_empty());
+}
+
+#[test]
+fn parse_markers_y_down() {
+    let (grid, markers) =
+        Grid::parse_with_markers("S..\n.#.\n..E\n", &['S', 'E'], |chr| {
+            Ok::<_, ()>(chr)
+        })
+        .unwrap();
+    let grid = grid.with_orientation(Orientation::YDown);
+    let markers = markers.with_orientation(Orientation::YDown);
+    assert_eq!(markers.first('S'), Some((0, 0).into()));
+    assert_eq!(markers.first('E'), Some((2, 2).into()));
+    assert_eq!(grid.get(markers.first('E').unwrap()), Some(&'E'));
+    assert_eq!(
+        markers.with_orientation(Orientation::YUp).first('S'),
+        Some((0, 2).into())
+    );
+}
+
+#[test]
+fn parse_markers_oriented() {
+    let text = "S..\n.#.\n..E\n";
+    for orientation in [Orientation::YUp, Orientation::YDown] {
+        let (grid, markers) = Grid::parse_with_markers_oriented(
+            text,
+            &['S', 'E'],
+            orientation,
+            Ok::<_, ()>,
+        )
+        .unwrap();
+        assert_eq!(grid.orientation(), orientation);
+        assert_eq!(grid.to_string(), text);
+        assert_eq!(grid.get(markers.first('S').unwrap()), Some(&'S'));
+        assert_eq!(grid.get(markers.first('E').unwrap()), Some(&'E'));
+    }
+
+    let (_, markers) = Grid::parse_with_markers_oriented(
+        text,
+        &['S'],
+        Orientation::YDown,
+        Ok::<_, ()>,
+    )
+    .unwrap();
+    assert_eq!(markers.first('S'), Some((0, 0).into()));
+    assert_eq!(
+        markers.with_orientation(Orientation::YUp).first('S'),
+        Some((0, 2).into())
+    );
+}
@@ -0,0 +1,172 @@
+use crate::{coord::Coord, Direction, Grid};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+#[cfg(test)]
+#[path = "pathfinding_test.rs"]
+mod pathfinding_test;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShortestPath {
+    pub distance: u64,
+    /// Every cell visited along the way, including both `start` and `goal`
+    pub path: Vec<Coord>,
+}
+
+/// Per-cell bookkeeping for a search, indexed by position rather than by the
+/// grid's storage order
+struct SearchState {
+    width: i64,
+    distance: Vec<Option<u64>>,
+    previous: Vec<Option<Coord>>,
+}
+
+impl SearchState {
+    fn new<T>(grid: &Grid<T>) -> Self {
+        let len = usize::try_from(grid.width() * grid.height()).unwrap();
+        Self {
+            width: grid.width(),
+            distance: vec![None; len],
+            previous: vec![None; len],
+        }
+    }
+
+    fn slot(&self, at: Coord) -> usize {
+        (at.x + self.width * at.y).try_into().unwrap()
+    }
+
+    fn distance(&self, at: Coord) -> Option<u64> {
+        self.distance[self.slot(at)]
+    }
+
+    fn visit(&mut self, at: Coord, distance: u64, previous: Option<Coord>) {
+        let slot = self.slot(at);
+        self.distance[slot] = Some(distance);
+        self.previous[slot] = previous;
+    }
+
+    fn into_path(self, goal: Coord) -> Option<ShortestPath> {
+        let distance = self.distance(goal)?;
+        let mut path = vec![goal];
+        let mut current = goal;
+        while let Some(previous) = self.previous[self.slot(current)] {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(ShortestPath { distance, path })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Unweighted search where every step between passable cells costs 1
+    pub fn bfs<P>(
+        &self,
+        start: impl Into<Coord>,
+        goal: impl Into<Coord>,
+        mut passable: P,
+    ) -> Option<ShortestPath>
+    where
+        P: FnMut((Coord, &T)) -> bool,
+    {
+        let start = start.into();
+        let goal = goal.into();
+        if self.get(goal).is_none()
+            || !self.get(start).is_some_and(|cell| passable((start, cell)))
+        {
+            return None;
+        }
+
+        let mut state = SearchState::new(self);
+        let mut queue = VecDeque::from([start]);
+        state.visit(start, 0, None);
+
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                break;
+            }
+            let distance = state.distance(current).unwrap();
+            for (neigh, cell) in self.iter_cardinal_neighbours(current) {
+                if state.distance(neigh).is_none() && passable((neigh, cell)) {
+                    state.visit(neigh, distance + 1, Some(current));
+                    queue.push_back(neigh);
+                }
+            }
+        }
+
+        state.into_path(goal)
+    }
+
+    /// Weighted search. `cost` is called with the cell being left and the
+    /// cell being entered, and is only consulted for passable cells.
+    pub fn dijkstra<P, C>(
+        &self,
+        start: impl Into<Coord>,
+        goal: impl Into<Coord>,
+        passable: P,
+        cost: C,
+    ) -> Option<ShortestPath>
+    where
+        P: FnMut((Coord, &T)) -> bool,
+        C: FnMut((Coord, &T), (Coord, &T)) -> u64,
+    {
+        self.astar(start, goal, passable, cost, |_| 0)
+    }
+
+    /// Weighted search guided by `heuristic`, which must never overestimate
+    /// the remaining cost to `goal` for the result to be optimal
+    pub fn astar<P, C, H>(
+        &self,
+        start: impl Into<Coord>,
+        goal: impl Into<Coord>,
+        mut passable: P,
+        mut cost: C,
+        mut heuristic: H,
+    ) -> Option<ShortestPath>
+    where
+        P: FnMut((Coord, &T)) -> bool,
+        C: FnMut((Coord, &T), (Coord, &T)) -> u64,
+        H: FnMut(Coord) -> u64,
+    {
+        let start = start.into();
+        let goal = goal.into();
+        if self.get(goal).is_none()
+            || !self.get(start).is_some_and(|cell| passable((start, cell)))
+        {
+            return None;
+        }
+
+        let mut state = SearchState::new(self);
+        let mut queue = BinaryHeap::new();
+        state.visit(start, 0, None);
+        queue.push(Reverse((heuristic(start), 0, start)));
+
+        while let Some(Reverse((_, distance, current))) = queue.pop() {
+            if current == goal {
+                break;
+            }
+            if state.distance(current).is_some_and(|best| best < distance) {
+                // Stale queue entry, a shorter route has already been found
+                continue;
+            }
+            let from = (current, self.get(current).unwrap());
+            for dir in Direction::iter() {
+                let Some(to) = self.get_neighbour(current, dir) else {
+                    continue;
+                };
+                if !passable(to) {
+                    continue;
+                }
+                let next = distance + cost(from, to);
+                if state.distance(to.0).is_none_or(|best| next < best) {
+                    state.visit(to.0, next, Some(current));
+                    queue.push(Reverse((next + heuristic(to.0), next, to.0)));
+                }
+            }
+        }
+
+        state.into_path(goal)
+    }
+}
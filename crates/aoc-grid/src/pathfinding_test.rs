@@ -0,0 +1,100 @@
+use super::*;
+
+#[rustfmt::skip]
+const MAZE: [char; 25] = [
+    '.', '.', '.', '#', '.',
+    '.', '#', '.', '#', '.',
+    '.', '#', '.', '.', '.',
+    '.', '#', '#', '#', '.',
+    '.', '.', '.', '.', '.',
+];
+
+fn open((_, cell): (Coord, &char)) -> bool {
+    *cell == '.'
+}
+
+fn assert_contiguous(path: &[Coord]) {
+    for pair in path.windows(2) {
+        let dx = (pair[0].x - pair[1].x).abs();
+        let dy = (pair[0].y - pair[1].y).abs();
+        assert_eq!(
+            dx + dy,
+            1,
+            "{} -> {} is not a single step",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn bfs_maze() {
+    let grid = Grid::new(MAZE, 5);
+    // Bottom-left to top-right: straight along the bottom then up the right
+    let found = grid.bfs((0, 0), (4, 4), open).unwrap();
+    assert_eq!(found.distance, 8);
+    assert_eq!(found.path.len(), 9);
+    assert_eq!(found.path.first(), Some(&Coord::from((0, 0))));
+    assert_eq!(found.path.last(), Some(&Coord::from((4, 4))));
+    assert_contiguous(&found.path);
+}
+
+#[test]
+fn bfs_start_is_goal() {
+    let grid = Grid::new(MAZE, 5);
+    let found = grid.bfs((0, 0), (0, 0), open).unwrap();
+    assert_eq!(found.distance, 0);
+    assert_eq!(found.path, [Coord::from((0, 0))]);
+}
+
+#[test]
+fn bfs_unreachable() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        '.', '#', '.',
+        '.', '#', '.',
+        '.', '#', '.',
+    ], 3);
+    assert!(grid.bfs((0, 0), (2, 2), open).is_none());
+    assert!(grid.bfs((1, 0), (2, 2), open).is_none());
+    assert!(grid.bfs((0, 0), (5, 5), open).is_none());
+}
+
+#[test]
+fn dijkstra_prefers_cheap_detour() {
+    #[rustfmt::skip]
+    let grid = Grid::<u64>::new([
+        1, 1, 1,
+        1, 9, 1,
+        1, 1, 1,
+    ], 3);
+    let found = grid
+        .dijkstra((0, 1), (2, 1), |_| true, |_, (_, cost)| *cost)
+        .unwrap();
+    assert_eq!(found.distance, 4);
+    assert_eq!(found.path.len(), 5);
+    assert!(!found.path.contains(&Coord::from((1, 1))));
+    assert_contiguous(&found.path);
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    #[rustfmt::skip]
+    let grid = Grid::<u64>::new([
+        1, 1, 6, 3, 7,
+        1, 3, 8, 1, 3,
+        2, 1, 3, 6, 5,
+        3, 6, 9, 4, 9,
+        7, 4, 6, 3, 4,
+    ], 5);
+    let goal = Coord::from((4, 0));
+    let cost = |_: (Coord, &u64), (_, cost): (Coord, &u64)| *cost;
+    let manhattan =
+        |at: Coord| ((goal.x - at.x).abs() + (goal.y - at.y).abs()) as u64;
+
+    let dijk = grid.dijkstra((0, 4), goal, |_| true, cost).unwrap();
+    let astar = grid.astar((0, 4), goal, |_| true, cost, manhattan).unwrap();
+    assert_eq!(dijk.distance, astar.distance);
+    assert_eq!(dijk.distance, 24);
+    assert_contiguous(&astar.path);
+}
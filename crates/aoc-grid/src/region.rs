@@ -0,0 +1,141 @@
+use crate::{coord::Coord, Direction, Grid, Space};
+use std::collections::HashSet;
+
+#[cfg(test)]
+#[path = "region_test.rs"]
+mod region_test;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub cells: Vec<Coord>,
+    pub area: usize,
+    /// Number of unit-length cell edges bordering another region or the
+    /// outside of the grid
+    pub perimeter: usize,
+    /// Number of distinct straight sides, treating a run of collinear
+    /// perimeter edges as a single side
+    pub sides: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Components {
+    /// Region id of every cell, indexing into `regions`
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+/// All cells of `space` reachable from `start` by moving between adjacent
+/// cells which satisfy `predicate`. Empty if `start` itself does not qualify.
+pub fn flood_fill<S, P>(
+    space: &S,
+    start: S::Coord,
+    mut predicate: P,
+) -> HashSet<S::Coord>
+where
+    S: Space,
+    P: FnMut((S::Coord, &S::Cell)) -> bool,
+{
+    let mut reached = HashSet::new();
+    if !space
+        .cell(start)
+        .is_some_and(|cell| predicate((start, cell)))
+    {
+        return reached;
+    }
+
+    let mut stack = vec![start];
+    reached.insert(start);
+    while let Some(current) = stack.pop() {
+        for neigh in space.adjacent(current) {
+            if reached.contains(&neigh) {
+                continue;
+            }
+            if space
+                .cell(neigh)
+                .is_some_and(|cell| predicate((neigh, cell)))
+            {
+                reached.insert(neigh);
+                stack.push(neigh);
+            }
+        }
+    }
+    reached
+}
+
+impl<T> Grid<T> {
+    /// All cells reachable from `start` through cardinal steps onto cells
+    /// satisfying `predicate`. Empty if `start` itself does not qualify.
+    pub fn flood_fill<P>(
+        &self,
+        start: impl Into<Coord>,
+        predicate: P,
+    ) -> HashSet<Coord>
+    where
+        P: FnMut((Coord, &T)) -> bool,
+    {
+        flood_fill(self, start.into(), predicate)
+    }
+
+    /// Split the grid into connected regions, where two cardinally adjacent
+    /// cells belong together if `same_region` holds for their values
+    pub fn components<F>(&self, mut same_region: F) -> Components
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut labels =
+            Grid::<Option<usize>>::new_default(self.width(), self.height())
+                .with_orientation(self.orientation());
+        let mut regions = Vec::new();
+
+        for (seed, _) in self.iter() {
+            if labels.get(seed).unwrap().is_some() {
+                continue;
+            }
+            let id = regions.len();
+            labels.set(seed, Some(id));
+
+            let mut cells = vec![seed];
+            let mut stack = vec![seed];
+            while let Some(current) = stack.pop() {
+                let value = self.get(current).unwrap();
+                for (neigh, cell) in self.iter_cardinal_neighbours(current) {
+                    if labels.get(neigh).unwrap().is_none()
+                        && same_region(value, cell)
+                    {
+                        labels.set(neigh, Some(id));
+                        cells.push(neigh);
+                        stack.push(neigh);
+                    }
+                }
+            }
+
+            regions.push(Region {
+                area: cells.len(),
+                perimeter: 0,
+                sides: 0,
+                cells,
+            });
+        }
+
+        let labels = labels.into_map(Option::unwrap);
+        for (id, region) in regions.iter_mut().enumerate() {
+            let outside = |at: Coord| labels.get(at) != Some(&id);
+            for &cell in &region.cells {
+                for dir in Direction::iter() {
+                    if !outside(cell + dir.vector()) {
+                        continue;
+                    }
+                    region.perimeter += 1;
+
+                    // Only count the side once, at the cell where it starts
+                    let behind = cell + dir.turn_left().vector();
+                    if outside(behind) || !outside(behind + dir.vector()) {
+                        region.sides += 1;
+                    }
+                }
+            }
+        }
+
+        Components { labels, regions }
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use crate::Orientation;
+
+#[rustfmt::skip]
+fn garden() -> Grid<char> {
+    Grid::new([
+        'A', 'A', 'A', 'A',
+        'B', 'B', 'C', 'D',
+        'B', 'B', 'C', 'C',
+        'E', 'E', 'E', 'C',
+    ], 4)
+}
+
+#[test]
+fn flood_fill_region() {
+    let grid = garden();
+    let reached = grid.flood_fill((0, 1), |(_, cell)| *cell == 'B');
+    assert_eq!(reached.len(), 4);
+    assert!(reached.contains(&(1, 2).into()));
+
+    let reached = grid.flood_fill((3, 0), |(_, cell)| *cell == 'C');
+    assert_eq!(reached.len(), 4);
+
+    assert!(grid.flood_fill((0, 0), |(_, cell)| *cell == 'B').is_empty());
+    assert!(grid.flood_fill((9, 9), |_| true).is_empty());
+    assert_eq!(grid.flood_fill((0, 0), |_| true).len(), 16);
+}
+
+#[test]
+fn components_garden() {
+    let grid = garden();
+    let components = grid.components(|a, b| a == b);
+    assert_eq!(components.regions.len(), 5);
+
+    let summary = components
+        .regions
+        .iter()
+        .map(|region| {
+            let plant = *grid.get(region.cells[0]).unwrap();
+            (plant, region.area, region.perimeter, region.sides)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            ('A', 4, 10, 4),
+            ('B', 4, 8, 4),
+            ('C', 4, 10, 8),
+            ('D', 1, 4, 4),
+            ('E', 3, 8, 4),
+        ]
+    );
+
+    for (id, region) in components.regions.iter().enumerate() {
+        for cell in &region.cells {
+            assert_eq!(components.labels.get(*cell), Some(&id));
+        }
+    }
+}
+
+#[test]
+fn components_sides_with_hole() {
+    #[rustfmt::skip]
+    let grid = Grid::new([
+        'A', 'A', 'A', 'A', 'A',
+        'A', 'X', 'A', 'X', 'A',
+        'A', 'A', 'A', 'A', 'A',
+        'A', 'X', 'A', 'X', 'A',
+        'A', 'A', 'A', 'A', 'A',
+    ], 5);
+    let components = grid.components(|a, b| a == b);
+    assert_eq!(components.regions.len(), 5);
+    let outer =
+        &components.regions[components.labels.get((0, 0)).copied().unwrap()];
+    assert_eq!(outer.area, 21);
+    assert_eq!(outer.perimeter, 36);
+    assert_eq!(outer.sides, 20);
+}
+
+#[test]
+fn components_y_down() {
+    let grid: Grid<char> = "AAB\nABB\n".parse().unwrap();
+    let flipped = grid.clone().with_orientation(Orientation::YDown);
+    for grid in [grid, flipped] {
+        let components = grid.components(|a, b| a == b);
+        let summary = components
+            .regions
+            .iter()
+            .map(|region| (region.area, region.perimeter, region.sides))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, [(3, 8, 6), (3, 8, 6)], "{:?}", grid.orientation());
+        for (id, region) in components.regions.iter().enumerate() {
+            for cell in &region.cells {
+                assert_eq!(components.labels.get(*cell), Some(&id));
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{coord::Coord, Grid};
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    fmt::{Display, Formatter},
+};
+
+#[cfg(feature = "colour")]
+use owo_colors::{OwoColorize, Style};
+
+#[cfg(test)]
+#[path = "render_test.rs"]
+mod render_test;
+
+type GlyphFn<'a, T> = Box<dyn Fn(&T) -> char + 'a>;
+#[cfg(feature = "colour")]
+type StyleFn<'a, T> = Box<dyn Fn(Coord, &T) -> Style + 'a>;
+
+/// Configurable text rendering of a grid, created by `Grid::renderer` and
+/// printed through `Display`
+pub struct Renderer<'a, T> {
+    grid: &'a Grid<T>,
+    glyph: GlyphFn<'a, T>,
+    highlighted: HashSet<Coord>,
+    marker: Option<char>,
+    #[cfg(feature = "colour")]
+    style: Option<StyleFn<'a, T>>,
+    #[cfg(feature = "colour")]
+    highlight_style: Option<Style>,
+}
+
+impl<T> Grid<T> {
+    /// Render each cell as the character returned by `glyph`
+    pub fn renderer<'a, G>(&'a self, glyph: G) -> Renderer<'a, T>
+    where
+        G: Fn(&T) -> char + 'a,
+    {
+        Renderer {
+            grid: self,
+            glyph: Box::new(glyph),
+            highlighted: HashSet::new(),
+            marker: None,
+            #[cfg(feature = "colour")]
+            style: None,
+            #[cfg(feature = "colour")]
+            highlight_style: None,
+        }
+    }
+}
+
+impl<'a, T> Renderer<'a, T> {
+    /// Add cells to the highlighted overlay, e.g. a path from a search
+    #[must_use]
+    pub fn highlight<I>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Coord>,
+    {
+        self.highlighted
+            .extend(cells.into_iter().map(|at| *at.borrow()));
+        self
+    }
+
+    /// Draw highlighted cells with this character instead of their glyph
+    #[must_use]
+    pub fn marker(mut self, marker: char) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    /// Colour every cell with the style returned by `style`
+    #[cfg(feature = "colour")]
+    #[must_use]
+    pub fn style<S>(mut self, style: S) -> Self
+    where
+        S: Fn(Coord, &T) -> Style + 'a,
+    {
+        self.style = Some(Box::new(style));
+        self
+    }
+
+    /// Colour highlighted cells with `style`, overriding the per-cell style
+    #[cfg(feature = "colour")]
+    #[must_use]
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = Some(style);
+        self
+    }
+
+    fn write_cell(
+        &self,
+        fmt: &mut Formatter<'_>,
+        at: Coord,
+        cell: &T,
+    ) -> std::fmt::Result {
+        let highlighted = self.highlighted.contains(&at);
+        let glyph = match self.marker {
+            Some(marker) if highlighted => marker,
+            _ => (self.glyph)(cell),
+        };
+
+        #[cfg(feature = "colour")]
+        {
+            let style = match (&self.style, self.highlight_style) {
+                (_, Some(style)) if highlighted => Some(style),
+                (Some(style), _) => Some(style(at, cell)),
+                _ => None,
+            };
+            if let Some(style) = style {
+                return write!(fmt, "{}", glyph.style(style));
+            }
+        }
+
+        write!(fmt, "{glyph}")
+    }
+}
+
+impl<T> Display for Renderer<'_, T> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        for (row_idx, row) in (0..).zip(self.grid.rows()) {
+            let y = self.grid.row_index(row_idx);
+            for (x, cell) in (0..).zip(row) {
+                self.write_cell(fmt, (x, y).into(), cell)?;
+            }
+            writeln!(fmt)?;
+        }
+        Ok(())
+    }
+}
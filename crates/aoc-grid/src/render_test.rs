@@ -0,0 +1,62 @@
+use super::*;
+
+#[rustfmt::skip]
+fn maze() -> Grid<bool> {
+    Grid::new([
+        false, true, false,
+        false, true, false,
+        false, false, false,
+    ], 3)
+}
+
+fn glyph(wall: &bool) -> char {
+    if *wall {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+#[test]
+fn render_plain() {
+    let grid = maze();
+    assert_eq!(grid.renderer(glyph).to_string(), ".#.\n.#.\n...\n");
+}
+
+#[test]
+fn render_path() {
+    let grid = maze();
+    let route = grid.bfs((0, 2), (2, 2), |(_, wall)| !wall).unwrap();
+    let rendered = grid.renderer(glyph).highlight(&route.path).marker('O');
+    assert_eq!(rendered.to_string(), "O#O\nO#O\nOOO\n");
+
+    // Without a marker the highlight has no effect on plain text
+    let rendered = grid.renderer(glyph).highlight([Coord::from((0, 0))]);
+    assert_eq!(rendered.to_string(), ".#.\n.#.\n...\n");
+}
+
+#[cfg(feature = "colour")]
+#[test]
+fn render_coloured() {
+    let grid = Grid::new([false, true], 2);
+    let rendered = grid
+        .renderer(glyph)
+        .style(|_, wall| {
+            if *wall {
+                Style::new().red()
+            } else {
+                Style::new()
+            }
+        })
+        .highlight([Coord::from((0, 0))])
+        .highlight_style(Style::new().on_yellow())
+        .to_string();
+    assert_eq!(
+        rendered,
+        format!(
+            "{}{}\n",
+            '.'.style(Style::new().on_yellow()),
+            '#'.style(Style::new().red())
+        )
+    );
+}
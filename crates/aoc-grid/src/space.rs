@@ -0,0 +1,27 @@
+use crate::{coord::Coord, Direction, Grid};
+use std::hash::Hash;
+
+/// A collection of cells with a notion of adjacency. Implemented by the grid
+/// containers so that searches and simulations can be shared between them.
+pub trait Space {
+    type Coord: Copy + Eq + Hash;
+    type Cell;
+
+    fn cell(&self, at: Self::Coord) -> Option<&Self::Cell>;
+
+    /// Positions next to `at`, whether or not they hold a cell
+    fn adjacent(&self, at: Self::Coord) -> impl Iterator<Item = Self::Coord>;
+}
+
+impl<T> Space for Grid<T> {
+    type Coord = Coord;
+    type Cell = T;
+
+    fn cell(&self, at: Coord) -> Option<&T> {
+        self.get(at)
+    }
+
+    fn adjacent(&self, at: Coord) -> impl Iterator<Item = Coord> {
+        Direction::iter().map(move |dir| at + dir.vector())
+    }
+}
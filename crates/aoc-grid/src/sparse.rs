@@ -0,0 +1,196 @@
+use crate::{coord::Coord, Direction, Direction8, Grid, Neighbourhood};
+use std::{collections::HashMap, fmt::Display};
+
+#[cfg(test)]
+#[path = "sparse_test.rs"]
+mod sparse_test;
+
+/// Unbounded grid which only stores the cells that have been written
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    inner: HashMap<Coord, T>,
+    bounds: Option<(Coord, Coord)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            inner: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Bottom-left and top-right corners of every cell ever written. The box
+    /// does not shrink when cells are removed.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> i64 {
+        self.bounds.map_or(0, |(min, max)| max.x - min.x + 1)
+    }
+
+    pub fn height(&self) -> i64 {
+        self.bounds.map_or(0, |(min, max)| max.y - min.y + 1)
+    }
+
+    pub fn get(&self, at: impl Into<Coord>) -> Option<&T> {
+        self.inner.get(&at.into())
+    }
+
+    pub fn get_mut(&mut self, at: impl Into<Coord>) -> Option<&mut T> {
+        self.inner.get_mut(&at.into())
+    }
+
+    pub fn set(&mut self, at: impl Into<Coord>, new: T) {
+        let at = at.into();
+        self.bounds = Some(match self.bounds {
+            None => (at, at),
+            Some((min, max)) => (
+                (min.x.min(at.x), min.y.min(at.y)).into(),
+                (max.x.max(at.x), max.y.max(at.y)).into(),
+            ),
+        });
+        self.inner.insert(at, new);
+    }
+
+    pub fn remove(&mut self, at: impl Into<Coord>) -> Option<T> {
+        self.inner.remove(&at.into())
+    }
+
+    pub fn get_neighbour(
+        &self,
+        at: impl Into<Coord>,
+        direction: Direction,
+    ) -> Option<(Coord, &T)> {
+        let at = at.into() + direction.vector();
+        self.get(at).map(|val| (at, val))
+    }
+
+    pub fn find<F>(&self, mut condition: F) -> Option<Coord>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.inner
+            .iter()
+            .find(|(_, cell)| condition(cell))
+            .map(|(at, _)| *at)
+    }
+
+    pub fn iter_cardinal_neighbours(
+        &self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let at = at.into();
+        Direction::iter().filter_map(move |dir| self.get_neighbour(at, dir))
+    }
+
+    pub fn iter_all_neighbours(
+        &self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &T)> {
+        let at = at.into();
+        Direction8::iter().filter_map(move |dir| {
+            let at = at + dir.vector();
+            self.get(at).map(|val| (at, val))
+        })
+    }
+
+    pub fn iter_neighbours<'a>(
+        &'a self,
+        at: impl Into<Coord>,
+        kind: &'a Neighbourhood,
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        let at = at.into();
+        kind.offsets().iter().filter_map(move |&offset| {
+            let at = at + offset;
+            self.get(at).map(|val| (at, val))
+        })
+    }
+
+    /// Occupied cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.inner.iter().map(|(at, value)| (*at, value))
+    }
+
+    /// Convert to a dense grid covering the bounding box, with its bottom-left
+    /// corner moved to the origin. Unset cells are filled with the default.
+    pub fn into_grid(mut self) -> Option<Grid<T>>
+    where
+        T: Default,
+    {
+        let (min, max) = self.bounds?;
+        let mut data = Vec::with_capacity(
+            usize::try_from(self.width() * self.height()).unwrap(),
+        );
+        for y in (min.y..=max.y).rev() {
+            for x in min.x..=max.x {
+                data.push(
+                    self.inner.remove(&(x, y).into()).unwrap_or_default(),
+                );
+            }
+        }
+        Some(Grid::new(data, self.width()))
+    }
+}
+
+/// Keeps the picture rather than the coordinates: sparse grids always have
+/// y pointing up, so the rows of a `YDown` grid are renumbered
+impl<T: Clone> From<&Grid<T>> for SparseGrid<T> {
+    fn from(grid: &Grid<T>) -> Self {
+        let mut sparse = Self::new();
+        for (row_idx, row) in (0..grid.height()).rev().zip(grid.rows()) {
+            for (x, cell) in (0..).zip(row) {
+                sparse.set((x, row_idx), cell.clone());
+            }
+        }
+        sparse
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut sparse = Self::new();
+        for (at, value) in iter {
+            sparse.set(at, value);
+        }
+        sparse
+    }
+}
+
+/// Renders the bounding box top row first, with unset cells shown as `.`
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in (min.y..=max.y).rev() {
+            for x in min.x..=max.x {
+                match self.get((x, y)) {
+                    Some(item) => write!(fmt, "{item}")?,
+                    None => write!(fmt, ".")?,
+                }
+            }
+            writeln!(fmt)?;
+        }
+        Ok(())
+    }
+}
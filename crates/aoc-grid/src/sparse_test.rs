@@ -0,0 +1,92 @@
+use super::*;
+use crate::Orientation;
+
+#[test]
+fn sparse_get_set() {
+    let mut grid = SparseGrid::new();
+    assert!(grid.is_empty());
+    assert_eq!(grid.bounds(), None);
+
+    grid.set((3, -2), 'a');
+    grid.set((-1, 4), 'b');
+    grid.set((3, -2), 'c');
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.get((3, -2)), Some(&'c'));
+    assert_eq!(grid.get((0, 0)), None);
+    assert_eq!(grid.bounds(), Some(((-1, -2).into(), (3, 4).into())));
+    assert_eq!((grid.width(), grid.height()), (5, 7));
+
+    assert_eq!(grid.remove((-1, 4)), Some('b'));
+    assert_eq!(grid.len(), 1);
+    assert_eq!(grid.width(), 5);
+}
+
+#[test]
+fn sparse_neighbours() {
+    let grid: SparseGrid<u8> =
+        [((0, 0).into(), 1), ((1, 0).into(), 2), ((1, 1).into(), 3)]
+            .into_iter()
+            .collect();
+
+    assert_eq!(
+        grid.get_neighbour((0, 0), Direction::Right),
+        Some(((1, 0).into(), &2))
+    );
+    assert_eq!(grid.iter_cardinal_neighbours((0, 0)).count(), 1);
+    assert_eq!(grid.iter_all_neighbours((0, 0)).count(), 2);
+    assert_eq!(
+        grid.iter_neighbours((0, 0), &Neighbourhood::Moore).count(),
+        2
+    );
+    assert_eq!(grid.find(|cell| *cell == 3), Some((1, 1).into()));
+}
+
+#[test]
+fn sparse_display() {
+    let mut grid = SparseGrid::new();
+    grid.set((-1, 1), '#');
+    grid.set((1, 0), '#');
+    assert_eq!(grid.to_string(), "#..\n..#\n");
+    assert_eq!(SparseGrid::<char>::new().to_string(), "");
+}
+
+#[test]
+fn sparse_dense_round_trip() {
+    #[rustfmt::skip]
+    let dense = Grid::new([
+        1_u8, 2, 3,
+        4, 5, 6,
+    ], 3);
+    let sparse = SparseGrid::from(&dense);
+    assert_eq!(sparse.len(), 6);
+    assert_eq!(sparse.get((0, 1)), Some(&1));
+    assert_eq!(sparse.get((2, 0)), Some(&6));
+    assert_eq!(sparse.to_string(), dense.to_string());
+    assert_eq!(sparse.into_grid().unwrap(), dense);
+}
+
+#[test]
+fn sparse_dense_round_trip_y_down() {
+    #[rustfmt::skip]
+    let dense = Grid::new([
+        1_u8, 2, 3,
+        4, 5, 6,
+    ], 3).with_orientation(Orientation::YDown);
+    let sparse = SparseGrid::from(&dense);
+    assert_eq!(sparse.to_string(), "123\n456\n");
+    assert_eq!(sparse.to_string(), dense.to_string());
+
+    let back = sparse.into_grid().unwrap();
+    assert_eq!(back.to_string(), dense.to_string());
+    assert_eq!(back.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+}
+
+#[test]
+fn sparse_into_grid_fills_default() {
+    let mut sparse = SparseGrid::new();
+    sparse.set((10, 10), 7_u8);
+    sparse.set((12, 9), 8);
+    let dense = sparse.into_grid().unwrap();
+    assert_eq!(dense.to_string(), "700\n008\n");
+    assert!(SparseGrid::<u8>::new().into_grid().is_none());
+}
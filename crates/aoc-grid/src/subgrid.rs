@@ -0,0 +1,177 @@
+use crate::{coord::Coord, Direction, Grid, Orientation};
+
+#[cfg(test)]
+#[path = "subgrid_test.rs"]
+mod subgrid_test;
+
+/// Read-only rectangular view into a grid. Coordinates are local to the
+/// view: its (0, 0) is the cell at `origin` in the underlying grid, and the
+/// axes point the same way as the grid's.
+#[derive(Debug)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coord,
+    width: i64,
+    height: i64,
+}
+
+impl<T> Clone for SubGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SubGrid<'_, T> {}
+
+impl<T> Grid<T> {
+    /// The `width` by `height` rectangle whose local (0, 0) is `origin`, or
+    /// `None` if it doesn't fit inside the grid
+    pub fn subgrid(
+        &self,
+        origin: impl Into<Coord>,
+        width: i64,
+        height: i64,
+    ) -> Option<SubGrid<'_, T>> {
+        let origin = origin.into();
+        let fits = width > 0
+            && height > 0
+            && origin.x >= 0
+            && origin.y >= 0
+            && origin.x + width <= self.width()
+            && origin.y + height <= self.height();
+        fits.then_some(SubGrid {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+
+    /// Every `width` by `height` view, ordered by where their top-left cell
+    /// falls in reading order. Empty if the window is larger than the grid.
+    pub fn windows(
+        &self,
+        width: i64,
+        height: i64,
+    ) -> impl Iterator<Item = SubGrid<'_, T>> {
+        assert!(width > 0 && height > 0, "Window must not be empty");
+        let rows = (self.height() - height + 1).max(0);
+        let cols = (self.width() - width + 1).max(0);
+        (0..rows).flat_map(move |row| {
+            let y = match self.orientation() {
+                Orientation::YUp => rows - 1 - row,
+                Orientation::YDown => row,
+            };
+            (0..cols).map(move |x| self.subgrid((x, y), width, height).unwrap())
+        })
+    }
+
+    /// Repeat the grid `nx` times across and `ny` times along the y axis.
+    /// Each cell of the result is `f(tile_x, tile_y, cell)`, where the tile
+    /// at (0, 0) lines up with the original grid.
+    #[must_use]
+    pub fn tile<F>(&self, nx: i64, ny: i64, mut f: F) -> Self
+    where
+        F: FnMut(i64, i64, &T) -> T,
+    {
+        assert!(nx > 0 && ny > 0, "Tile counts must be positive");
+        let (width, height) = (self.width(), self.height());
+        Self::from_fn_oriented(
+            width * nx,
+            height * ny,
+            self.orientation(),
+            |at| {
+                let cell = self.get((at.x % width, at.y % height)).unwrap();
+                f(at.x / width, at.y / height, cell)
+            },
+        )
+    }
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    pub fn width(&self) -> i64 {
+        self.width
+    }
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    /// Position of the local (0, 0) in the underlying grid
+    pub fn origin(&self) -> Coord {
+        self.origin
+    }
+
+    /// Convert a local coordinate to one in the underlying grid
+    pub fn grid_coord(&self, at: impl Into<Coord>) -> Coord {
+        let at = at.into();
+        Coord::from((self.origin.x + at.x, self.origin.y + at.y))
+    }
+
+    pub fn get(&self, at: impl Into<Coord>) -> Option<&'a T> {
+        let at = at.into();
+        if at.x < 0 || at.y < 0 || at.x >= self.width || at.y >= self.height {
+            return None;
+        }
+        self.grid.get(self.grid_coord(at))
+    }
+
+    pub fn get_neighbour(
+        &self,
+        at: impl Into<Coord>,
+        direction: Direction,
+    ) -> Option<(Coord, &'a T)> {
+        let at = at.into() + self.grid.orientation().vector(direction);
+        self.get(at).map(|val| (at, val))
+    }
+
+    /// Local y coordinate of the `row`th row from the top
+    fn row_y(&self, row: i64) -> i64 {
+        match self.grid.orientation() {
+            Orientation::YUp => self.height - 1 - row,
+            Orientation::YDown => row,
+        }
+    }
+
+    /// Cells with local coordinates, in the same order as `Grid::iter`
+    pub fn iter(self) -> impl Iterator<Item = (Coord, &'a T)> {
+        (0..self.height).flat_map(move |row| {
+            let y = self.row_y(row);
+            (0..self.width).map(move |x| {
+                let at = Coord::from((x, y));
+                (at, self.get(at).unwrap())
+            })
+        })
+    }
+
+    /// Rows from top to bottom, as in `Grid::rows`
+    pub fn rows(self) -> impl Iterator<Item = &'a [T]> {
+        let start = usize::try_from(self.origin.x).unwrap();
+        let end = start + usize::try_from(self.width).unwrap();
+        (0..self.height).map(move |row| {
+            let y = self.origin.y + self.row_y(row);
+            &self.grid.row(y).unwrap()[start..end]
+        })
+    }
+
+    pub fn find<F>(self, mut condition: F) -> Option<Coord>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find(|(_, cell)| condition(cell))
+            .map(|(at, _)| at)
+    }
+}
+
+impl<T: Clone> SubGrid<'_, T> {
+    /// Copy the view out into a grid of its own
+    pub fn to_grid(&self) -> Grid<T> {
+        let grid = *self;
+        Grid::from_fn_oriented(
+            self.width,
+            self.height,
+            self.grid.orientation(),
+            |at| grid.get(at).unwrap().clone(),
+        )
+    }
+}
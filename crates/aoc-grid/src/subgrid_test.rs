@@ -0,0 +1,113 @@
+use super::*;
+
+#[rustfmt::skip]
+const LETTERS: [char; 12] = [
+    'a', 'b', 'c', 'd',
+    'e', 'f', 'g', 'h',
+    'i', 'j', 'k', 'l',
+];
+
+#[test]
+fn subgrid_local_coordinates() {
+    let grid = Grid::new(LETTERS, 4);
+    assert!(grid.subgrid((2, 0), 3, 1).is_none());
+    assert!(grid.subgrid((0, 1), 1, 3).is_none());
+
+    let view = grid.subgrid((1, 0), 2, 2).unwrap();
+    assert_eq!(view.get((0, 0)), Some(&'j'));
+    assert_eq!(view.get((1, 1)), Some(&'g'));
+    assert_eq!(view.get((2, 0)), None);
+    assert_eq!(view.grid_coord((1, 1)), Coord::from((2, 1)));
+    assert_eq!(view.find(|&chr| chr == 'k'), Some((1, 0).into()));
+    assert_eq!(
+        view.get_neighbour((0, 0), Direction::Up),
+        Some(((0, 1).into(), &'f'))
+    );
+    assert_eq!(view.get_neighbour((0, 0), Direction::Left), None);
+
+    let rows = view.rows().collect::<Vec<_>>();
+    assert_eq!(rows, [['f', 'g'], ['j', 'k']]);
+    assert_eq!(view.to_grid(), Grid::new(['f', 'g', 'j', 'k'], 2));
+    assert!(view.iter().eq(view.to_grid().iter()));
+}
+
+#[test]
+fn subgrid_y_down() {
+    let grid = Grid::new(LETTERS, 4).with_orientation(Orientation::YDown);
+    let view = grid.subgrid((1, 0), 2, 2).unwrap();
+    assert_eq!(view.get((0, 0)), Some(&'b'));
+    assert_eq!(view.rows().collect::<Vec<_>>(), [['b', 'c'], ['f', 'g']]);
+    assert!(view.iter().eq(view.to_grid().iter()));
+    assert_eq!(
+        view.get_neighbour((0, 0), Direction::Down),
+        Some(((0, 1).into(), &'f'))
+    );
+}
+
+#[test]
+fn windows_in_reading_order() {
+    for orientation in [Orientation::YUp, Orientation::YDown] {
+        let grid = Grid::new(LETTERS, 4).with_orientation(orientation);
+        let corners = grid
+            .windows(3, 2)
+            .map(|view| *view.rows().next().unwrap().first().unwrap())
+            .collect::<String>();
+        assert_eq!(corners, "abef");
+        assert_eq!(grid.windows(4, 3).count(), 1);
+        assert_eq!(grid.windows(5, 1).count(), 0);
+    }
+}
+
+#[test]
+fn windows_find_crossed_mas() {
+    // 2024 day 4 part two: count 3x3 windows with MAS on both diagonals
+    let grid = Grid::<char>::parse(
+        "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\n\
+         XXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX\n",
+        Ok::<_, ()>,
+    )
+    .unwrap();
+    let is_mas = |a: char, b: char, c: char| {
+        b == 'A' && matches!((a, c), ('M', 'S') | ('S', 'M'))
+    };
+    let found = grid
+        .windows(3, 3)
+        .filter(|view| {
+            let at = |x, y| *view.get((x, y)).unwrap();
+            is_mas(at(0, 0), at(1, 1), at(2, 2))
+                && is_mas(at(0, 2), at(1, 1), at(2, 0))
+        })
+        .count();
+    assert_eq!(found, 9);
+}
+
+#[test]
+fn tile_with_increasing_risk() {
+    // 2021 day 15 part two: each tile step right or down adds one, wrapping
+    // from 9 back to 1. The puzzle's y axis points down the page.
+    let grid = Grid::new([8_u8], 1).with_orientation(Orientation::YDown);
+    let tiled = grid.tile(5, 5, |tx, ty, &risk| {
+        let risk = i64::from(risk) + tx + ty;
+        u8::try_from((risk - 1) % 9 + 1).unwrap()
+    });
+    assert_eq!(tiled.width(), 5);
+    assert_eq!(tiled.row(0).unwrap(), [8, 9, 1, 2, 3]);
+    assert_eq!(tiled.row(4).unwrap(), [3, 4, 5, 6, 7]);
+    assert_eq!(tiled.rows().next().unwrap(), [8, 9, 1, 2, 3]);
+
+    let grid = Grid::new(LETTERS, 4);
+    let tiled = grid.tile(
+        2,
+        3,
+        |tx, ty, &chr| {
+            if (tx, ty) == (0, 0) {
+                chr
+            } else {
+                '.'
+            }
+        },
+    );
+    assert_eq!((tiled.width(), tiled.height()), (8, 9));
+    assert_eq!(tiled.subgrid((0, 0), 4, 3).unwrap().to_grid(), grid);
+    assert_eq!(tiled.iter().filter(|(_, chr)| **chr == '.').count(), 60);
+}
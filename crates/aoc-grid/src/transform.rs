@@ -0,0 +1,71 @@
+use crate::Grid;
+
+#[cfg(test)]
+#[path = "transform_test.rs"]
+mod transform_test;
+
+/// Symmetry operations. These all work on the grid as it is displayed, so
+/// "horizontal" and "clockwise" refer to the printed picture.
+impl<T: Clone> Grid<T> {
+    /// Build a grid of the given width and height where each output cell at
+    /// (row, col) is copied from the input cell at `source(row, col)`
+    fn remap<F>(&self, width: i64, height: i64, source: F) -> Self
+    where
+        F: Fn(i64, i64) -> (i64, i64),
+    {
+        let rows = self.rows().collect::<Vec<_>>();
+        let data = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let (src_row, src_col) = source(row, col);
+                rows[usize::try_from(src_row).unwrap()]
+                    [usize::try_from(src_col).unwrap()]
+                .clone()
+            })
+            .collect::<Vec<_>>();
+        Self::new(data, width).with_orientation(self.orientation())
+    }
+
+    /// Rows of the result are the columns of the input, read top to bottom
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height(), self.width(), |row, col| (col, row))
+    }
+
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height();
+        self.remap(height, self.width(), |row, col| (height - 1 - col, row))
+    }
+
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width();
+        self.remap(self.height(), width, |row, col| (col, width - 1 - row))
+    }
+
+    /// Mirror left-to-right
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width();
+        self.remap(width, self.height(), |row, col| (row, width - 1 - col))
+    }
+
+    /// Mirror top-to-bottom
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height();
+        self.remap(self.width(), height, |row, col| (height - 1 - row, col))
+    }
+
+    /// All eight rotations and reflections, starting with an unchanged copy.
+    /// The first four are successive clockwise rotations and the last four
+    /// are the same rotations of the horizontally flipped grid.
+    pub fn orientations(&self) -> impl Iterator<Item = Self> + '_ {
+        let rotations = |start: Self| {
+            std::iter::successors(Some(start), |grid| Some(grid.rotate_cw()))
+                .take(4)
+        };
+        rotations(self.clone()).chain(rotations(self.flip_horizontal()))
+    }
+}
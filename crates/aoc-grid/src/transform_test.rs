@@ -0,0 +1,64 @@
+use super::*;
+
+#[rustfmt::skip]
+fn sample() -> Grid<u8> {
+    Grid::new([
+        1, 2, 3,
+        4, 5, 6,
+    ], 3)
+}
+
+#[test]
+fn transpose() {
+    let grid = sample().transpose();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert_eq!(grid.to_string(), "14\n25\n36\n");
+    assert_eq!(grid.transpose(), sample());
+}
+
+#[test]
+fn rotate() {
+    let cw = sample().rotate_cw();
+    assert_eq!((cw.width(), cw.height()), (2, 3));
+    assert_eq!(cw.to_string(), "41\n52\n63\n");
+
+    let ccw = sample().rotate_ccw();
+    assert_eq!(ccw.to_string(), "36\n25\n14\n");
+
+    assert_eq!(cw.rotate_ccw(), sample());
+    assert_eq!(cw.rotate_cw().rotate_cw().rotate_cw(), sample());
+    assert_eq!(sample().rotate_cw().rotate_cw().to_string(), "654\n321\n");
+}
+
+#[test]
+fn flip() {
+    assert_eq!(sample().flip_horizontal().to_string(), "321\n654\n");
+    assert_eq!(sample().flip_vertical().to_string(), "456\n123\n");
+    assert_eq!(sample().flip_vertical().flip_vertical(), sample());
+    assert_eq!(
+        sample().flip_horizontal().flip_vertical(),
+        sample().rotate_cw().rotate_cw()
+    );
+}
+
+#[test]
+fn orientations() {
+    let all = sample().orientations().collect::<Vec<_>>();
+    assert_eq!(all.len(), 8);
+    assert_eq!(all[0], sample());
+    for (idx, grid) in all.iter().enumerate() {
+        assert!(
+            all[idx + 1..].iter().all(|other| other != grid),
+            "orientation {idx} is repeated"
+        );
+    }
+    assert!(all.contains(&sample().transpose()));
+    assert!(all.contains(&sample().flip_vertical()));
+
+    #[rustfmt::skip]
+    let symmetric = Grid::new([
+        1_u8, 1,
+        1, 1,
+    ], 2);
+    assert!(symmetric.orientations().all(|grid| grid == symmetric));
+}
@@ -0,0 +1,124 @@
+use crate::{coord::Coord, Direction, Direction8, Grid, Neighbourhood};
+
+#[cfg(test)]
+#[path = "wrapping_test.rs"]
+mod wrapping_test;
+
+/// Which axes of a grid join up with their opposite edge
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Wrap {
+    /// Walking off the left or right edge comes back on the other side
+    Horizontal,
+    /// Walking off the top or bottom edge comes back on the other side
+    Vertical,
+    /// Full torus
+    Both,
+}
+
+impl Wrap {
+    fn horizontal(self) -> bool {
+        matches!(self, Self::Horizontal | Self::Both)
+    }
+
+    fn vertical(self) -> bool {
+        matches!(self, Self::Vertical | Self::Both)
+    }
+}
+
+/// Read-only view of a grid whose lookups wrap around the edges
+#[derive(Debug)]
+pub struct WrappingGrid<'a, T> {
+    grid: &'a Grid<T>,
+    wrap: Wrap,
+}
+
+impl<T> Clone for WrappingGrid<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for WrappingGrid<'_, T> {}
+
+impl<T> Grid<T> {
+    pub fn wrapping(&self, wrap: Wrap) -> WrappingGrid<'_, T> {
+        WrappingGrid { grid: self, wrap }
+    }
+
+    /// Bring `at` back inside the grid along the wrapping axes. Returns
+    /// `None` if it is still out of bounds along an axis that doesn't wrap.
+    pub fn wrap_coord(
+        &self,
+        at: impl Into<Coord>,
+        wrap: Wrap,
+    ) -> Option<Coord> {
+        let mut at = at.into();
+        if wrap.horizontal() {
+            at.x = at.x.rem_euclid(self.width());
+        }
+        if wrap.vertical() {
+            at.y = at.y.rem_euclid(self.height());
+        }
+        self.get(at).map(|_| at)
+    }
+}
+
+impl<'a, T> WrappingGrid<'a, T> {
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    pub fn wrap(&self) -> Wrap {
+        self.wrap
+    }
+
+    pub fn wrap_coord(&self, at: impl Into<Coord>) -> Option<Coord> {
+        self.grid.wrap_coord(at, self.wrap)
+    }
+
+    pub fn get(&self, at: impl Into<Coord>) -> Option<&'a T> {
+        self.wrap_coord(at).and_then(|at| self.grid.get(at))
+    }
+
+    /// The returned coordinate is the wrapped position inside the grid
+    pub fn get_neighbour(
+        &self,
+        at: impl Into<Coord>,
+        direction: Direction,
+    ) -> Option<(Coord, &'a T)> {
+        self.get_offset(at.into() + self.grid.orientation().vector(direction))
+    }
+
+    fn get_offset(&self, at: Coord) -> Option<(Coord, &'a T)> {
+        let at = self.wrap_coord(at)?;
+        self.grid.get(at).map(|val| (at, val))
+    }
+
+    pub fn iter_cardinal_neighbours(
+        self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        let at = at.into();
+        Direction::iter().filter_map(move |dir| self.get_neighbour(at, dir))
+    }
+
+    pub fn iter_all_neighbours(
+        self,
+        at: impl Into<Coord>,
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        let at = at.into();
+        Direction8::iter().filter_map(move |dir| {
+            self.get_offset(at + self.grid.orientation().vector(dir))
+        })
+    }
+
+    pub fn iter_neighbours(
+        self,
+        at: impl Into<Coord>,
+        kind: &'a Neighbourhood,
+    ) -> impl Iterator<Item = (Coord, &'a T)> {
+        let at = at.into();
+        kind.oriented_offsets(self.grid.orientation())
+            .filter_map(move |offset| self.get_offset(at + offset))
+    }
+}
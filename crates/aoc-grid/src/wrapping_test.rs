@@ -0,0 +1,94 @@
+use super::*;
+use crate::Orientation;
+
+#[rustfmt::skip]
+fn sample() -> Grid<u16> {
+    Grid::new([
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ], 3)
+}
+
+#[test]
+fn wrap_coord_axes() {
+    let grid = sample();
+    assert_eq!(
+        grid.wrap_coord((3, 1), Wrap::Horizontal),
+        Some((0, 1).into())
+    );
+    assert_eq!(
+        grid.wrap_coord((-1, 1), Wrap::Horizontal),
+        Some((2, 1).into())
+    );
+    assert_eq!(grid.wrap_coord((1, 3), Wrap::Horizontal), None);
+    assert_eq!(
+        grid.wrap_coord((1, -4), Wrap::Vertical),
+        Some((1, 2).into())
+    );
+    assert_eq!(grid.wrap_coord((4, 1), Wrap::Vertical), None);
+    assert_eq!(grid.wrap_coord((-7, 10), Wrap::Both), Some((2, 1).into()));
+}
+
+#[test]
+fn wrapping_get() {
+    let grid = sample();
+    let torus = grid.wrapping(Wrap::Both);
+    assert_eq!(torus.get((3, 3)), Some(&7));
+    assert_eq!(torus.get((-1, -1)), Some(&3));
+    assert_eq!(torus.get((300, 0)), grid.get((0, 0)));
+
+    let cylinder = grid.wrapping(Wrap::Horizontal);
+    assert_eq!(cylinder.get((5, 2)), Some(&3));
+    assert_eq!(cylinder.get((0, 3)), None);
+}
+
+#[test]
+fn wrapping_neighbours() {
+    let grid = sample();
+    let torus = grid.wrapping(Wrap::Both);
+    assert_eq!(
+        torus.get_neighbour((0, 0), Direction::Left),
+        Some(((2, 0).into(), &9))
+    );
+
+    let neigh = torus
+        .iter_cardinal_neighbours((0, 0))
+        .map(|(_, val)| *val)
+        .collect::<Vec<_>>();
+    assert_eq!(neigh, [4, 9, 1, 8]);
+    assert_eq!(torus.iter_all_neighbours((0, 0)).count(), 8);
+    assert_eq!(
+        torus
+            .iter_neighbours((2, 2), &Neighbourhood::Moore)
+            .map(|(_, val)| *val)
+            .sum::<u16>(),
+        45 - 3
+    );
+
+    let cylinder = grid.wrapping(Wrap::Horizontal);
+    assert_eq!(cylinder.iter_cardinal_neighbours((0, 0)).count(), 3);
+    assert_eq!(cylinder.iter_all_neighbours((0, 0)).count(), 5);
+}
+
+#[test]
+fn wrapping_neighbours_y_down() {
+    let grid = sample().with_orientation(Orientation::YDown);
+    let cylinder = grid.wrapping(Wrap::Vertical);
+    // Above the top row is the bottom row, whichever way y points
+    assert_eq!(
+        cylinder
+            .iter_neighbours((1, 0), &Neighbourhood::VonNeumann)
+            .next(),
+        Some(((1, 2).into(), &8))
+    );
+    // Custom offsets are in the grid's coordinates, so y - 1 is up here
+    let up = Neighbourhood::Custom(vec![(0, -1).into()]);
+    assert_eq!(
+        cylinder.iter_neighbours((1, 0), &up).collect::<Vec<_>>(),
+        cylinder
+            .iter_cardinal_neighbours((1, 0))
+            .take(1)
+            .collect::<Vec<_>>()
+    );
+}
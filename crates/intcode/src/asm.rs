@@ -0,0 +1,499 @@
+use crate::{Instruction, Mode, Opcode};
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+#[cfg(test)]
+#[path = "asm_test.rs"]
+mod asm_test;
+
+/// A problem with the assembly source. Every variant records the 1-based
+/// `line` it was found on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AsmError {
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    UnknownMnemonic {
+        line: usize,
+        mnemonic: String,
+    },
+    OperandCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    WriteInImmediateMode {
+        line: usize,
+    },
+    UndefinedLabel {
+        line: usize,
+        label: String,
+    },
+    DuplicateLabel {
+        line: usize,
+        label: String,
+    },
+    /// A numeric label, as written in front of each line of a listing,
+    /// doesn't match where the line was actually assembled
+    AddressMismatch {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl AsmError {
+    pub fn line(&self) -> usize {
+        match *self {
+            Self::Syntax { line, .. }
+            | Self::UnknownMnemonic { line, .. }
+            | Self::OperandCount { line, .. }
+            | Self::WriteInImmediateMode { line }
+            | Self::UndefinedLabel { line, .. }
+            | Self::DuplicateLabel { line, .. }
+            | Self::AddressMismatch { line, .. } => line,
+        }
+    }
+}
+
+impl Display for AsmError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Syntax { message, .. } => write!(fmt, "{message}"),
+            Self::UnknownMnemonic { mnemonic, .. } => {
+                write!(fmt, "Unknown mnemonic `{mnemonic}`")
+            }
+            Self::OperandCount {
+                expected, found, ..
+            } => write!(fmt, "Expected {expected} operands, found {found}"),
+            Self::WriteInImmediateMode { .. } => {
+                write!(fmt, "Written operand is in immediate mode")
+            }
+            Self::UndefinedLabel { label, .. } => {
+                write!(fmt, "Undefined label `{label}`")
+            }
+            Self::DuplicateLabel { label, .. } => {
+                write!(fmt, "Label `{label}` is already defined")
+            }
+            Self::AddressMismatch {
+                expected, actual, ..
+            } => write!(fmt, "Expected address {expected}, found {actual}"),
+        }?;
+        write!(fmt, " on line {}", self.line())
+    }
+}
+
+impl Error for AsmError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Str(String),
+    Punct(char),
+    Arrow,
+}
+
+impl Display for Token {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(fmt, "`{word}`"),
+            Self::Str(string) => write!(fmt, "{string:?}"),
+            Self::Punct(chr) => write!(fmt, "`{chr}`"),
+            Self::Arrow => write!(fmt, "`->`"),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(chr) = chars.next() {
+        match chr {
+            ';' => break,
+            '-' if chars.peek() == Some(&'>') => {
+                chars.next();
+                tokens.push(Token::Arrow);
+            }
+            '[' | ']' | '#' | ',' | ':' | '+' | '-' => {
+                tokens.push(Token::Punct(chr));
+            }
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => string.push(match chars.next() {
+                            Some('n') => '\n',
+                            Some('t') => '\t',
+                            Some(chr @ ('\\' | '"')) => chr,
+                            _ => return Err("Invalid escape".to_string()),
+                        }),
+                        Some(chr) => string.push(chr),
+                        None => return Err("Unterminated string".to_string()),
+                    }
+                }
+                tokens.push(Token::Str(string));
+            }
+            _ if chr.is_whitespace() => {}
+            _ if chr.is_alphanumeric() || chr == '_' => {
+                let mut word = String::from(chr);
+                while let Some(&chr) = chars.peek() {
+                    if !chr.is_alphanumeric() && chr != '_' {
+                        break;
+                    }
+                    word.push(chr);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+            _ => return Err(format!("Unexpected `{chr}`")),
+        }
+    }
+    Ok(tokens)
+}
+
+/// A constant plus or minus any number of label addresses
+#[derive(Clone, Debug, Default)]
+struct Expr {
+    constant: i64,
+    labels: Vec<(bool, String)>,
+}
+
+impl Expr {
+    fn resolve(
+        &self,
+        line: usize,
+        labels: &HashMap<String, usize>,
+    ) -> Result<i64, AsmError> {
+        let mut value = self.constant;
+        for (negate, label) in &self.labels {
+            let address =
+                labels.get(label).ok_or_else(|| AsmError::UndefinedLabel {
+                    line,
+                    label: label.clone(),
+                })?;
+            let address = i64::try_from(*address).unwrap();
+            value = if *negate {
+                value.checked_sub(address)
+            } else {
+                value.checked_add(address)
+            }
+            .ok_or_else(|| AsmError::Syntax {
+                line,
+                message: "Value out of range".to_string(),
+            })?;
+        }
+        Ok(value)
+    }
+}
+
+enum Statement {
+    Op {
+        instruction: Instruction,
+        operands: Vec<Expr>,
+    },
+    Data(Vec<Expr>),
+}
+
+impl Statement {
+    fn len(&self) -> usize {
+        match self {
+            Self::Op { operands, .. } => 1 + operands.len(),
+            Self::Data(values) => values.len(),
+        }
+    }
+}
+
+/// Parser for the tokens of a single line
+struct Parser {
+    line: usize,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(line: usize, text: &str) -> Result<Self, AsmError> {
+        let tokens = tokenize(text)
+            .map_err(|message| AsmError::Syntax { line, message })?;
+        Ok(Self {
+            line,
+            tokens,
+            pos: 0,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn eat(&mut self, punct: char) -> bool {
+        let found = self.peek() == Some(&Token::Punct(punct));
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn unexpected(&self, token: Option<Token>) -> AsmError {
+        self.syntax(match token {
+            Some(token) => format!("Unexpected {token}"),
+            None => "Unexpected end of line".to_string(),
+        })
+    }
+
+    /// A leading `name:`, if there is one
+    fn label(&mut self) -> Option<String> {
+        match &self.tokens[self.pos..] {
+            [Token::Word(word), Token::Punct(':'), ..] => {
+                let word = word.clone();
+                self.pos += 2;
+                Some(word)
+            }
+            _ => None,
+        }
+    }
+
+    /// Consume a leading `+` or `-`, returning whether it negates
+    fn sign(&mut self) -> Option<bool> {
+        let negate = match self.peek() {
+            Some(Token::Punct('+')) => false,
+            Some(Token::Punct('-')) => true,
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(negate)
+    }
+
+    fn syntax(&self, message: String) -> AsmError {
+        AsmError::Syntax {
+            line: self.line,
+            message,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, AsmError> {
+        let mut expr = Expr::default();
+        let mut negate = self.sign().unwrap_or(false);
+        loop {
+            match self.next() {
+                Some(Token::Word(word))
+                    if word.starts_with(|chr: char| chr.is_ascii_digit()) =>
+                {
+                    let sign = if negate { "-" } else { "" };
+                    let value =
+                        format!("{sign}{word}").parse().map_err(|_| {
+                            self.syntax(format!("Invalid number `{word}`"))
+                        })?;
+                    expr.constant =
+                        expr.constant.checked_add(value).ok_or_else(|| {
+                            self.syntax("Value out of range".to_string())
+                        })?;
+                }
+                Some(Token::Word(word)) if word != "rb" => {
+                    expr.labels.push((negate, word));
+                }
+                token => return Err(self.unexpected(token)),
+            }
+            match self.sign() {
+                Some(next) => negate = next,
+                None => return Ok(expr),
+            }
+        }
+    }
+
+    fn operand(&mut self) -> Result<(Mode, Expr), AsmError> {
+        match self.next() {
+            Some(Token::Punct('#')) => Ok((Mode::Immediate, self.expr()?)),
+            Some(Token::Punct('[')) => {
+                let operand = if self.peek() == Some(&Token::Word("rb".into()))
+                {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::Punct(']')) => {
+                            (Mode::Relative, Expr::default())
+                        }
+                        Some(Token::Punct('+' | '-')) => {
+                            (Mode::Relative, self.expr()?)
+                        }
+                        _ => return Err(self.unexpected(self.peek().cloned())),
+                    }
+                } else {
+                    (Mode::Position, self.expr()?)
+                };
+                match self.next() {
+                    Some(Token::Punct(']')) => Ok(operand),
+                    token => Err(self.unexpected(token)),
+                }
+            }
+            token => Err(self.unexpected(token)),
+        }
+    }
+
+    fn data(&mut self) -> Result<Vec<Expr>, AsmError> {
+        let mut values = Vec::new();
+        loop {
+            if let Some(Token::Str(string)) = self.peek() {
+                values.extend(string.chars().map(|chr| Expr {
+                    constant: i64::from(u32::from(chr)),
+                    labels: Vec::new(),
+                }));
+                self.pos += 1;
+            } else {
+                values.push(self.expr()?);
+            }
+            if self.at_end() {
+                return Ok(values);
+            }
+            if !self.eat(',') {
+                return Err(self.unexpected(self.peek().cloned()));
+            }
+        }
+    }
+
+    /// The instruction or directive after any labels, or `None` for a line
+    /// with nothing else on it
+    fn statement(&mut self) -> Result<Option<Statement>, AsmError> {
+        let name = match self.next() {
+            None => return Ok(None),
+            Some(Token::Word(name)) => name,
+            token => return Err(self.unexpected(token)),
+        };
+        if name.eq_ignore_ascii_case("data") {
+            return Ok(Some(Statement::Data(self.data()?)));
+        }
+        let opcode = Opcode::from_mnemonic(&name).ok_or_else(|| {
+            AsmError::UnknownMnemonic {
+                line: self.line,
+                mnemonic: name.clone(),
+            }
+        })?;
+
+        let mut modes = [Mode::Position; 3];
+        let mut operands = Vec::new();
+        let mut arrow = None;
+        while !self.at_end() {
+            if self.peek() == Some(&Token::Arrow) {
+                arrow = Some(operands.len());
+                self.pos += 1;
+            }
+            let (mode, operand) = self.operand()?;
+            if let Some(slot) = modes.get_mut(operands.len()) {
+                *slot = mode;
+            }
+            operands.push(operand);
+            self.eat(',');
+        }
+
+        if operands.len() != opcode.params() {
+            return Err(AsmError::OperandCount {
+                line: self.line,
+                expected: opcode.params(),
+                found: operands.len(),
+            });
+        }
+        if arrow.is_some() && arrow != opcode.write_param() {
+            return Err(self.syntax(
+                "`->` must come before the written operand".to_string(),
+            ));
+        }
+        if let Some(param) = opcode.write_param()
+            && modes[param] == Mode::Immediate
+        {
+            return Err(AsmError::WriteInImmediateMode { line: self.line });
+        }
+
+        let instruction = Instruction { opcode, modes };
+        Ok(Some(Statement::Op {
+            instruction,
+            operands,
+        }))
+    }
+}
+
+/// Assemble a program written one instruction or directive per line:
+///
+/// ```text
+/// ; Output 1 if the input is 8, else 0
+///        in -> [value]
+///        eq [value] #8 -> [value]
+///        out [value]
+///        hlt
+/// value: data 0
+/// ```
+///
+/// Operands are `#n` for immediate, `[n]` for position and `[rb+n]` for
+/// relative mode, where `n` may add or subtract labels. `->` may mark the
+/// operand that is written. `data` emits a list of values and strings.
+/// A numeric label checks the address of its line, so a `listing`
+/// assembles back to the program it was made from.
+pub fn assemble(source: &str) -> Result<Vec<i64>, AsmError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+
+    for (idx, text) in source.lines().enumerate() {
+        let line = idx + 1;
+        let mut parser = Parser::new(line, text)?;
+        while let Some(label) = parser.label() {
+            if label.starts_with(|chr: char| chr.is_ascii_digit()) {
+                let expected = label.parse().map_err(|_| AsmError::Syntax {
+                    line,
+                    message: format!("Invalid address `{label}`"),
+                })?;
+                if expected != address {
+                    return Err(AsmError::AddressMismatch {
+                        line,
+                        expected,
+                        actual: address,
+                    });
+                }
+            } else if label == "rb" {
+                return Err(AsmError::Syntax {
+                    line,
+                    message: "`rb` can't be used as a label".to_string(),
+                });
+            } else if labels.insert(label.clone(), address).is_some() {
+                return Err(AsmError::DuplicateLabel { line, label });
+            }
+        }
+        if let Some(statement) = parser.statement()? {
+            address += statement.len();
+            statements.push((line, statement));
+        }
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (line, statement) in statements {
+        match statement {
+            Statement::Op {
+                instruction,
+                operands,
+            } => {
+                program.push(instruction.encode());
+                for operand in operands {
+                    program.push(operand.resolve(line, &labels)?);
+                }
+            }
+            Statement::Data(values) => {
+                for value in values {
+                    program.push(value.resolve(line, &labels)?);
+                }
+            }
+        }
+    }
+    Ok(program)
+}
@@ -0,0 +1,151 @@
+use super::*;
+use crate::{Machine, State, listing, parse_program};
+
+#[test]
+fn assemble_with_labels() {
+    // Day 5's "is the input equal to 8" program
+    let source = "
+        ; Output 1 if the input is 8, else 0
+                in -> [input]
+                eq [input] [eight] -> [input]
+                out [input]
+                hlt
+        input:  data -1
+        eight:  data 8
+    ";
+    assert_eq!(
+        assemble(source).unwrap(),
+        [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]
+    );
+}
+
+#[test]
+fn assemble_modes() {
+    let source = "
+        ARB #5
+        out [rb-3]
+        add [rb], #1, -> [rb+2]
+        mul #-2 [next+1] [next]
+        next: jf #0 #start - 1
+        start:
+    ";
+    assert_eq!(
+        assemble(source).unwrap(),
+        [
+            109, 5, 204, -3, 21201, 0, 1, 2, 102, -2, 13, 12, 1106, 0, 14
+        ]
+    );
+}
+
+#[test]
+fn assemble_data() {
+    assert_eq!(
+        assemble("data \"Hi;\\n\", 0, end - 2\nend:").unwrap(),
+        [72, 105, 59, 10, 0, 4]
+    );
+}
+
+#[test]
+fn assembled_program_runs() {
+    // Count down from the input to 1
+    let source = "
+        in -> [count]
+        loop:   out [count]
+                add [count] #-1 -> [count]
+                jt [count] #loop
+                hlt
+        count:  data 0
+    ";
+    let mut machine = Machine::new(&assemble(source).unwrap());
+    machine.push_input(3);
+    while machine.process() == Ok(State::OutputReady) {}
+    assert_eq!(machine.state(), State::Term);
+    assert_eq!(machine.output(), [3, 2, 1]);
+}
+
+#[test]
+fn assemble_errors() {
+    let error = |source| assemble(source).unwrap_err();
+    assert_eq!(
+        error("hlt\nnop"),
+        AsmError::UnknownMnemonic {
+            line: 2,
+            mnemonic: "nop".to_string()
+        }
+    );
+    assert_eq!(
+        error("add #1 #2"),
+        AsmError::OperandCount {
+            line: 1,
+            expected: 3,
+            found: 2
+        }
+    );
+    assert_eq!(error("in #5"), AsmError::WriteInImmediateMode { line: 1 });
+    assert_eq!(
+        error("jt #1 [there]"),
+        AsmError::UndefinedLabel {
+            line: 1,
+            label: "there".to_string()
+        }
+    );
+    assert_eq!(
+        error("a: hlt\na: hlt"),
+        AsmError::DuplicateLabel {
+            line: 2,
+            label: "a".to_string()
+        }
+    );
+    assert_eq!(
+        error("0000: hlt\n0002: hlt"),
+        AsmError::AddressMismatch {
+            line: 2,
+            expected: 2,
+            actual: 1
+        }
+    );
+    for source in [
+        "add -> #1 #2 [3]",
+        "out [rb 3]",
+        "out 3",
+        "data",
+        "data 1 2",
+        "data \"open",
+        "rb: hlt",
+        "out #99999999999999999999",
+        "hlt $",
+    ] {
+        assert!(
+            matches!(error(source), AsmError::Syntax { line: 1, .. }),
+            "{source}"
+        );
+    }
+    assert_eq!(
+        error("\n\nout [rb+]").to_string(),
+        "Unexpected `]` on line 3"
+    );
+}
+
+#[test]
+fn listing_round_trips() {
+    let programs = [
+        vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+        vec![1002, 4, 3, 4, 33],
+        vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8],
+        vec![109, -1, 204, 1, 99, 1105, 1, 12, 6, 7],
+        vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101,
+            0, 99,
+        ],
+        parse_program(include_str!("../../../2019/2/input.txt")).unwrap(),
+        parse_program(include_str!("../../../2019/5/input.txt")).unwrap(),
+        parse_program(include_str!("../../../2019/7/input.txt")).unwrap(),
+        parse_program(include_str!("../../../2019/9/input.txt")).unwrap(),
+    ];
+    for program in programs {
+        let text = listing(&program);
+        let assembled = assemble(&text).unwrap();
+        assert_eq!(assembled, program, "{text}");
+        assert_eq!(listing(&assembled), text);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Instruction, Mode};
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)]
+#[path = "disasm_test.rs"]
+mod disasm_test;
+
+/// One line of a disassembly listing
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Line {
+    Op {
+        address: usize,
+        instruction: Instruction,
+        params: Vec<i64>,
+    },
+    /// A run of values that don't decode as instructions
+    Data { address: usize, values: Vec<i64> },
+}
+
+impl Line {
+    pub fn address(&self) -> usize {
+        match *self {
+            Self::Op { address, .. } | Self::Data { address, .. } => address,
+        }
+    }
+}
+
+/// Decode `program` from the start, one instruction after another. Values
+/// that aren't a valid instruction, or whose parameters would run off the
+/// end of the program, are gathered into `Line::Data`.
+pub fn disassemble(program: &[i64]) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut address = 0;
+
+    while address < program.len() {
+        let decoded = Instruction::decode(address, program[address])
+            .ok()
+            .filter(|instr| address + instr.opcode.params() < program.len());
+        let Some(instruction) = decoded else {
+            match lines.last_mut() {
+                Some(Line::Data { values, .. }) => {
+                    values.push(program[address])
+                }
+                _ => lines.push(Line::Data {
+                    address,
+                    values: vec![program[address]],
+                }),
+            }
+            address += 1;
+            continue;
+        };
+
+        let end = address + 1 + instruction.opcode.params();
+        lines.push(Line::Op {
+            address,
+            instruction,
+            params: program[address + 1..end].to_vec(),
+        });
+        address = end;
+    }
+
+    lines
+}
+
+/// The whole program as text, one line per instruction or data run, in a
+/// form that `assemble` turns back into the same program
+pub fn listing(program: &[i64]) -> String {
+    disassemble(program)
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect()
+}
+
+fn write_operand(
+    fmt: &mut Formatter,
+    mode: Mode,
+    value: i64,
+) -> std::fmt::Result {
+    match mode {
+        Mode::Position => write!(fmt, "[{value}]"),
+        Mode::Immediate => write!(fmt, "#{value}"),
+        Mode::Relative => write!(fmt, "[rb{value:+}]"),
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        write!(fmt, "{:04}: ", self.address())?;
+        match self {
+            Self::Op {
+                instruction,
+                params,
+                ..
+            } => {
+                let opcode = instruction.opcode;
+                write!(fmt, "{}", opcode.mnemonic().to_uppercase())?;
+                for (idx, (&mode, &value)) in
+                    instruction.modes.iter().zip(params).enumerate()
+                {
+                    if opcode.write_param() == Some(idx) {
+                        write!(fmt, " ->")?;
+                    }
+                    write!(fmt, " ")?;
+                    write_operand(fmt, mode, value)?;
+                }
+                Ok(())
+            }
+            Self::Data { values, .. } => {
+                write!(fmt, "DATA")?;
+                for (idx, value) in values.iter().enumerate() {
+                    let sep = if idx == 0 { " " } else { ", " };
+                    write!(fmt, "{sep}{value}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use super::*;
+
+#[test]
+fn listing_annotates_modes() {
+    let program = [1001, 224, -110, 224, 3, 225, 204, -3, 109, 19, 99];
+    assert_eq!(
+        listing(&program),
+        "0000: ADD [224] #-110 -> [224]\n\
+         0004: IN -> [225]\n\
+         0006: OUT [rb-3]\n\
+         0008: ARB #19\n\
+         0010: HLT\n"
+    );
+}
+
+#[test]
+fn undecodable_values_are_data() {
+    // Day 5 keeps a block of zeroes after its first halt, and an
+    // instruction can't run off the end of the program
+    let program = [1105, 0, 99999, 99, 0, 0, 677, 1, 2, -5, 1, 2];
+    let lines = disassemble(&program);
+    assert_eq!(
+        lines,
+        [
+            Line::Op {
+                address: 0,
+                instruction: Instruction::decode(0, 1105).unwrap(),
+                params: vec![0, 99999],
+            },
+            Line::Op {
+                address: 3,
+                instruction: Instruction::decode(3, 99).unwrap(),
+                params: vec![],
+            },
+            Line::Data {
+                address: 4,
+                values: vec![0, 0, 677],
+            },
+            Line::Op {
+                address: 7,
+                instruction: Instruction::decode(7, 1).unwrap(),
+                params: vec![2, -5, 1],
+            },
+            Line::Data {
+                address: 11,
+                values: vec![2],
+            },
+        ]
+    );
+    assert_eq!(lines[0].to_string(), "0000: JT #0 #99999");
+    assert_eq!(lines[2].to_string(), "0004: DATA 0, 0, 677");
+}
+
+#[test]
+fn disassembly_covers_every_value() {
+    let program = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+    let covered = disassemble(&program)
+        .iter()
+        .map(|line| match line {
+            Line::Op { params, .. } => params.len() + 1,
+            Line::Data { values, .. } => values.len(),
+        })
+        .sum::<usize>();
+    assert_eq!(covered, program.len());
+}
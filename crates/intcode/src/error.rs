@@ -0,0 +1,104 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+/// A fault raised by a running program. Every variant records the `pc` of
+/// the instruction that failed and the raw `instruction` value found there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntcodeError {
+    UnknownOpcode {
+        pc: usize,
+        instruction: i64,
+    },
+    /// `param` is 1-based, counting the parameters after the instruction
+    InvalidMode {
+        pc: usize,
+        instruction: i64,
+        param: usize,
+        mode: i64,
+    },
+    NegativeAddress {
+        pc: usize,
+        instruction: i64,
+        address: i64,
+    },
+    /// An address calculation overflowed, or gave an address too large to
+    /// represent on this platform
+    AddressOverflow {
+        pc: usize,
+        instruction: i64,
+    },
+    WriteInImmediateMode {
+        pc: usize,
+        instruction: i64,
+        param: usize,
+    },
+    RelativeBaseUnderflow {
+        pc: usize,
+        instruction: i64,
+        base: i64,
+    },
+    ArithmeticOverflow {
+        pc: usize,
+        instruction: i64,
+    },
+}
+
+impl IntcodeError {
+    pub fn pc(&self) -> usize {
+        match *self {
+            Self::UnknownOpcode { pc, .. }
+            | Self::InvalidMode { pc, .. }
+            | Self::NegativeAddress { pc, .. }
+            | Self::AddressOverflow { pc, .. }
+            | Self::WriteInImmediateMode { pc, .. }
+            | Self::RelativeBaseUnderflow { pc, .. }
+            | Self::ArithmeticOverflow { pc, .. } => pc,
+        }
+    }
+
+    pub fn instruction(&self) -> i64 {
+        match *self {
+            Self::UnknownOpcode { instruction, .. }
+            | Self::InvalidMode { instruction, .. }
+            | Self::NegativeAddress { instruction, .. }
+            | Self::AddressOverflow { instruction, .. }
+            | Self::WriteInImmediateMode { instruction, .. }
+            | Self::RelativeBaseUnderflow { instruction, .. }
+            | Self::ArithmeticOverflow { instruction, .. } => instruction,
+        }
+    }
+}
+
+impl Display for IntcodeError {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match *self {
+            Self::UnknownOpcode { .. } => write!(fmt, "Unknown opcode"),
+            Self::InvalidMode { param, mode, .. } => {
+                write!(fmt, "Invalid mode {mode} for parameter {param}")
+            }
+            Self::NegativeAddress { address, .. } => {
+                write!(fmt, "Negative address {address}")
+            }
+            Self::AddressOverflow { .. } => write!(fmt, "Address overflow"),
+            Self::WriteInImmediateMode { param, .. } => {
+                write!(fmt, "Parameter {param} is written in immediate mode")
+            }
+            Self::RelativeBaseUnderflow { base, .. } => {
+                write!(fmt, "Relative base underflow to {base}")
+            }
+            Self::ArithmeticOverflow { .. } => {
+                write!(fmt, "Arithmetic overflow")
+            }
+        }?;
+        write!(
+            fmt,
+            " at pc={} (instruction {})",
+            self.pc(),
+            self.instruction()
+        )
+    }
+}
+
+impl Error for IntcodeError {}
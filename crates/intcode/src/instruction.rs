@@ -0,0 +1,190 @@
+use crate::IntcodeError;
+
+#[cfg(test)]
+#[path = "instruction_test.rs"]
+mod instruction_test;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Opcode {
+    Add,
+    Mul,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+impl Opcode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        Some(match code {
+            1 => Self::Add,
+            2 => Self::Mul,
+            3 => Self::Input,
+            4 => Self::Output,
+            5 => Self::JumpIfTrue,
+            6 => Self::JumpIfFalse,
+            7 => Self::LessThan,
+            8 => Self::Equals,
+            9 => Self::AdjustBase,
+            99 => Self::Halt,
+            _ => return None,
+        })
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Self::Add => 1,
+            Self::Mul => 2,
+            Self::Input => 3,
+            Self::Output => 4,
+            Self::JumpIfTrue => 5,
+            Self::JumpIfFalse => 6,
+            Self::LessThan => 7,
+            Self::Equals => 8,
+            Self::AdjustBase => 9,
+            Self::Halt => 99,
+        }
+    }
+
+    /// Number of parameters following the instruction
+    pub fn params(self) -> usize {
+        match self {
+            Self::Add | Self::Mul | Self::LessThan | Self::Equals => 3,
+            Self::JumpIfTrue | Self::JumpIfFalse => 2,
+            Self::Input | Self::Output | Self::AdjustBase => 1,
+            Self::Halt => 0,
+        }
+    }
+
+    /// Short name used in listings and by the assembler
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Mul => "mul",
+            Self::Input => "in",
+            Self::Output => "out",
+            Self::JumpIfTrue => "jt",
+            Self::JumpIfFalse => "jf",
+            Self::LessThan => "lt",
+            Self::Equals => "eq",
+            Self::AdjustBase => "arb",
+            Self::Halt => "hlt",
+        }
+    }
+
+    /// Inverse of `mnemonic`, ignoring case
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Some(match mnemonic.to_ascii_lowercase().as_str() {
+            "add" => Self::Add,
+            "mul" => Self::Mul,
+            "in" => Self::Input,
+            "out" => Self::Output,
+            "jt" => Self::JumpIfTrue,
+            "jf" => Self::JumpIfFalse,
+            "lt" => Self::LessThan,
+            "eq" => Self::Equals,
+            "arb" => Self::AdjustBase,
+            "hlt" => Self::Halt,
+            _ => return None,
+        })
+    }
+
+    /// The parameter, counting from 0, naming the address that is written
+    pub fn write_param(self) -> Option<usize> {
+        match self {
+            Self::Add | Self::Mul | Self::LessThan | Self::Equals => Some(2),
+            Self::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl Mode {
+    pub fn from_code(code: i64) -> Option<Self> {
+        match code {
+            0 => Some(Self::Position),
+            1 => Some(Self::Immediate),
+            2 => Some(Self::Relative),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> i64 {
+        match self {
+            Self::Position => 0,
+            Self::Immediate => 1,
+            Self::Relative => 2,
+        }
+    }
+}
+
+/// An opcode along with the mode of each of its parameters. Modes past the
+/// opcode's parameter count are always `Position`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Decode the raw value found at `pc`. The mode digits must all be
+    /// valid, with none left over past the last parameter.
+    pub fn decode(pc: usize, instruction: i64) -> Result<Self, IntcodeError> {
+        let opcode = Opcode::from_code(instruction % 100)
+            .filter(|_| instruction >= 0)
+            .ok_or(IntcodeError::UnknownOpcode { pc, instruction })?;
+
+        let mut modes = [Mode::Position; 3];
+        let mut digits = instruction / 100;
+        let mut param = 0;
+        while digits != 0 {
+            let mode = digits % 10;
+            let invalid = IntcodeError::InvalidMode {
+                pc,
+                instruction,
+                param: param + 1,
+                mode,
+            };
+            if param >= opcode.params() {
+                return Err(invalid);
+            }
+            modes[param] = Mode::from_code(mode).ok_or(invalid)?;
+            digits /= 10;
+            param += 1;
+        }
+
+        if let Some(param) = opcode.write_param()
+            && modes[param] == Mode::Immediate
+        {
+            return Err(IntcodeError::WriteInImmediateMode {
+                pc,
+                instruction,
+                param: param + 1,
+            });
+        }
+
+        Ok(Self { opcode, modes })
+    }
+
+    /// The raw value that `decode` turns back into this instruction
+    pub fn encode(&self) -> i64 {
+        self.modes
+            .iter()
+            .rev()
+            .fold(0, |digits, mode| digits * 10 + mode.code())
+            * 100
+            + self.opcode.code()
+    }
+}
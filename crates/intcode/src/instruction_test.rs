@@ -0,0 +1,78 @@
+use super::*;
+
+#[test]
+fn decode_modes() {
+    let decoded = Instruction::decode(0, 1002).unwrap();
+    assert_eq!(decoded.opcode, Opcode::Mul);
+    assert_eq!(
+        decoded.modes,
+        [Mode::Position, Mode::Immediate, Mode::Position]
+    );
+
+    let decoded = Instruction::decode(0, 21108).unwrap();
+    assert_eq!(decoded.opcode, Opcode::Equals);
+    assert_eq!(
+        decoded.modes,
+        [Mode::Immediate, Mode::Immediate, Mode::Relative]
+    );
+
+    assert_eq!(Instruction::decode(0, 99).unwrap().opcode, Opcode::Halt);
+}
+
+#[test]
+fn decode_errors() {
+    assert_eq!(
+        Instruction::decode(4, 42),
+        Err(IntcodeError::UnknownOpcode {
+            pc: 4,
+            instruction: 42
+        })
+    );
+    assert_eq!(
+        Instruction::decode(4, -1),
+        Err(IntcodeError::UnknownOpcode {
+            pc: 4,
+            instruction: -1
+        })
+    );
+    assert_eq!(
+        Instruction::decode(0, 1301),
+        Err(IntcodeError::InvalidMode {
+            pc: 0,
+            instruction: 1301,
+            param: 1,
+            mode: 3
+        })
+    );
+    // Output only has one parameter, so a second mode digit is an error
+    assert_eq!(
+        Instruction::decode(0, 1104),
+        Err(IntcodeError::InvalidMode {
+            pc: 0,
+            instruction: 1104,
+            param: 2,
+            mode: 1
+        })
+    );
+    assert_eq!(
+        Instruction::decode(0, 10001),
+        Err(IntcodeError::WriteInImmediateMode {
+            pc: 0,
+            instruction: 10001,
+            param: 3
+        })
+    );
+}
+
+#[test]
+fn encode_round_trips() {
+    for raw in [1, 99, 1002, 1105, 203, 21108, 22201, 109] {
+        assert_eq!(Instruction::decode(0, raw).unwrap().encode(), raw);
+    }
+    let instruction = Instruction {
+        opcode: Opcode::from_mnemonic("ADD").unwrap(),
+        modes: [Mode::Relative, Mode::Immediate, Mode::Position],
+    };
+    assert_eq!(instruction.encode(), 1201);
+    assert_eq!(Opcode::from_mnemonic("nop"), None);
+}
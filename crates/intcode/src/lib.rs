@@ -0,0 +1,13 @@
+mod asm;
+mod disasm;
+mod error;
+mod instruction;
+mod machine;
+mod memory;
+
+pub use asm::*;
+pub use disasm::*;
+pub use error::*;
+pub use instruction::*;
+pub use machine::*;
+pub use memory::*;
@@ -0,0 +1,256 @@
+use crate::{Instruction, IntcodeError, Memory, Mode, Opcode};
+use std::{collections::VecDeque, num::ParseIntError, str::FromStr};
+
+#[cfg(test)]
+#[path = "machine_test.rs"]
+mod machine_test;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum State {
+    Running,
+    Term,
+    OutputReady,
+    InputWaiting,
+}
+
+/// An Intcode computer: the program's memory plus queues for its input and
+/// output
+#[derive(Clone, Debug)]
+pub struct Machine {
+    memory: Memory,
+    pc: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: Vec<i64>,
+    state: State,
+}
+
+impl Machine {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: Memory::new(program),
+            pc: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: Vec::new(),
+            state: State::Running,
+        }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
+
+    pub fn memory(&self) -> &Memory {
+        &self.memory
+    }
+
+    pub fn read(&self, addr: usize) -> i64 {
+        self.memory.read(addr)
+    }
+
+    pub fn write(&mut self, addr: usize, value: i64) {
+        self.memory.write(addr, value);
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    /// Everything output so far that hasn't been popped
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    /// Take the oldest output
+    pub fn pop_output(&mut self) -> Option<i64> {
+        (!self.output.is_empty()).then(|| self.output.remove(0))
+    }
+
+    /// The raw value of parameter `param`, counting from 0
+    fn param(&self, param: usize) -> i64 {
+        self.memory.read(self.pc + 1 + param)
+    }
+
+    /// The address parameter `param` refers to in position or relative mode
+    fn address(
+        &self,
+        instr: Instruction,
+        raw: i64,
+        param: usize,
+    ) -> Result<usize, IntcodeError> {
+        let value = self.param(param);
+        let address = match instr.modes[param] {
+            Mode::Relative => self.relative_base.checked_add(value).ok_or(
+                IntcodeError::AddressOverflow {
+                    pc: self.pc,
+                    instruction: raw,
+                },
+            )?,
+            _ => value,
+        };
+        self.to_address(raw, address)
+    }
+
+    fn to_address(
+        &self,
+        raw: i64,
+        address: i64,
+    ) -> Result<usize, IntcodeError> {
+        if address < 0 {
+            return Err(IntcodeError::NegativeAddress {
+                pc: self.pc,
+                instruction: raw,
+                address,
+            });
+        }
+        usize::try_from(address).map_err(|_| IntcodeError::AddressOverflow {
+            pc: self.pc,
+            instruction: raw,
+        })
+    }
+
+    fn load(
+        &self,
+        instr: Instruction,
+        raw: i64,
+        param: usize,
+    ) -> Result<i64, IntcodeError> {
+        match instr.modes[param] {
+            Mode::Immediate => Ok(self.param(param)),
+            _ => Ok(self.memory.read(self.address(instr, raw, param)?)),
+        }
+    }
+
+    fn store(
+        &mut self,
+        instr: Instruction,
+        raw: i64,
+        value: i64,
+    ) -> Result<(), IntcodeError> {
+        let param = instr.opcode.write_param().unwrap();
+        let addr = self.address(instr, raw, param)?;
+        self.memory.write(addr, value);
+        Ok(())
+    }
+
+    /// Execute a single instruction. On error the machine is left as it was
+    /// before the faulting instruction.
+    pub fn step(&mut self) -> Result<State, IntcodeError> {
+        let raw = self.memory.read(self.pc);
+        let instr = Instruction::decode(self.pc, raw)?;
+        let overflow = IntcodeError::ArithmeticOverflow {
+            pc: self.pc,
+            instruction: raw,
+        };
+        let mut next_pc = self.pc + 1 + instr.opcode.params();
+        let mut state = State::Running;
+
+        match instr.opcode {
+            Opcode::Add => {
+                let a = self.load(instr, raw, 0)?;
+                let b = self.load(instr, raw, 1)?;
+                let sum = a.checked_add(b).ok_or(overflow)?;
+                self.store(instr, raw, sum)?;
+            }
+            Opcode::Mul => {
+                let a = self.load(instr, raw, 0)?;
+                let b = self.load(instr, raw, 1)?;
+                let product = a.checked_mul(b).ok_or(overflow)?;
+                self.store(instr, raw, product)?;
+            }
+            Opcode::Input => {
+                // Check the address first so that a fault doesn't lose input
+                self.address(instr, raw, 0)?;
+                if let Some(value) = self.input.pop_front() {
+                    self.store(instr, raw, value)?;
+                } else {
+                    // No input available, return and wait for more
+                    state = State::InputWaiting;
+                    next_pc = self.pc;
+                }
+            }
+            Opcode::Output => {
+                let value = self.load(instr, raw, 0)?;
+                self.output.push(value);
+                state = State::OutputReady;
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let value = self.load(instr, raw, 0)?;
+                let target = self.load(instr, raw, 1)?;
+                if (value != 0) == (instr.opcode == Opcode::JumpIfTrue) {
+                    next_pc = self.to_address(raw, target)?;
+                }
+            }
+            Opcode::LessThan => {
+                let a = self.load(instr, raw, 0)?;
+                let b = self.load(instr, raw, 1)?;
+                self.store(instr, raw, i64::from(a < b))?;
+            }
+            Opcode::Equals => {
+                let a = self.load(instr, raw, 0)?;
+                let b = self.load(instr, raw, 1)?;
+                self.store(instr, raw, i64::from(a == b))?;
+            }
+            Opcode::AdjustBase => {
+                let offset = self.load(instr, raw, 0)?;
+                let base = self.relative_base.checked_add(offset).ok_or(
+                    IntcodeError::AddressOverflow {
+                        pc: self.pc,
+                        instruction: raw,
+                    },
+                )?;
+                if base < 0 {
+                    return Err(IntcodeError::RelativeBaseUnderflow {
+                        pc: self.pc,
+                        instruction: raw,
+                        base,
+                    });
+                }
+                self.relative_base = base;
+            }
+            Opcode::Halt => {
+                state = State::Term;
+                next_pc = self.pc;
+            }
+        }
+
+        self.pc = next_pc;
+        self.state = state;
+        Ok(state)
+    }
+
+    /// Run until the machine halts, produces output or needs more input
+    pub fn process(&mut self) -> Result<State, IntcodeError> {
+        loop {
+            let state = self.step()?;
+            if state != State::Running {
+                return Ok(state);
+            }
+        }
+    }
+}
+
+/// Parse the comma-separated program text of a puzzle input
+pub fn parse_program(inp: &str) -> Result<Vec<i64>, ParseIntError> {
+    inp.trim()
+        .split(',')
+        .map(|value| value.trim().parse())
+        .collect()
+}
+
+impl FromStr for Machine {
+    type Err = ParseIntError;
+
+    fn from_str(inp: &str) -> Result<Self, Self::Err> {
+        parse_program(inp).map(|program| Self::new(&program))
+    }
+}
@@ -0,0 +1,63 @@
+use intcode::{Machine, State, assemble, listing, parse_program};
+use std::{env, error::Error, fs, process::ExitCode};
+
+const USAGE: &str = "\
+usage: intcode run <program> [input...]
+       intcode disasm <program>
+       intcode asm <source>";
+
+fn run(program: &[i64], inputs: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut machine = Machine::new(program);
+    for input in inputs {
+        machine.push_input(input.parse()?);
+    }
+    loop {
+        match machine.process()? {
+            State::OutputReady => {
+                while let Some(value) = machine.pop_output() {
+                    println!("{value}");
+                }
+            }
+            State::Term => return Ok(()),
+            State::InputWaiting => {
+                return Err("program is waiting for more input".into());
+            }
+            State::Running => unreachable!(),
+        }
+    }
+}
+
+fn dispatch(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let [command, path, rest @ ..] = args else {
+        return Err(USAGE.into());
+    };
+    let text = fs::read_to_string(path)?;
+    if command == "asm" && rest.is_empty() {
+        let program = assemble(&text)?;
+        let values: Vec<String> =
+            program.iter().map(ToString::to_string).collect();
+        println!("{}", values.join(","));
+        return Ok(());
+    }
+
+    let program = parse_program(&text)?;
+    match command.as_str() {
+        "run" => run(&program, rest),
+        "disasm" if rest.is_empty() => {
+            print!("{}", listing(&program));
+            Ok(())
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match dispatch(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
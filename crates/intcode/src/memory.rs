@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[path = "memory_test.rs"]
+mod memory_test;
+
+/// Addresses below this are kept in a vector, anything above in a map
+const DENSE_LIMIT: usize = 1 << 20;
+
+/// Intcode memory. Every address reads as 0 until it is written. The low
+/// addresses, where programs keep their code and working data, are stored
+/// contiguously and grow on demand; very large addresses are paged sparsely
+/// so that a far-off relative base doesn't allocate everything below it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Memory {
+    dense: Vec<i64>,
+    sparse: HashMap<usize, i64>,
+}
+
+impl Memory {
+    pub fn new(program: &[i64]) -> Self {
+        let split = program.len().min(DENSE_LIMIT);
+        Self {
+            dense: program[..split].to_owned(),
+            sparse: (split..).zip(program[split..].iter().copied()).collect(),
+        }
+    }
+
+    pub fn read(&self, addr: usize) -> i64 {
+        if addr < DENSE_LIMIT {
+            self.dense.get(addr).copied().unwrap_or(0)
+        } else {
+            self.sparse.get(&addr).copied().unwrap_or(0)
+        }
+    }
+
+    pub fn write(&mut self, addr: usize, value: i64) {
+        if addr < DENSE_LIMIT {
+            if addr >= self.dense.len() {
+                self.dense.resize(addr + 1, 0);
+            }
+            self.dense[addr] = value;
+        } else {
+            self.sparse.insert(addr, value);
+        }
+    }
+
+    /// One past the highest address written to, or loaded with the program
+    pub fn len(&self) -> usize {
+        let sparse_end = self.sparse.keys().max().map_or(0, |addr| addr + 1);
+        self.dense.len().max(sparse_end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
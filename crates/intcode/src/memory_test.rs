@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn memory_reads_zero_until_written() {
+    let mut memory = Memory::new(&[1, 2, 3]);
+    assert_eq!(memory.read(2), 3);
+    assert_eq!(memory.read(3), 0);
+    assert_eq!(memory.read(DENSE_LIMIT + 5), 0);
+    assert_eq!(memory.len(), 3);
+
+    memory.write(10, 7);
+    assert_eq!(memory.read(10), 7);
+    assert_eq!(memory.read(9), 0);
+    assert_eq!(memory.len(), 11);
+}
+
+#[test]
+fn memory_pages_far_addresses() {
+    let mut memory = Memory::new(&[99]);
+    let far = usize::MAX / 2;
+    memory.write(far, -4);
+    assert_eq!(memory.read(far), -4);
+    assert_eq!(memory.read(far - 1), 0);
+    assert_eq!(memory.len(), far + 1);
+    // Nothing below the far write was allocated
+    assert_eq!(memory.dense.len(), 1);
+}
+
+#[test]
+fn memory_loads_past_dense_limit() {
+    let mut program = vec![0; DENSE_LIMIT + 2];
+    program[DENSE_LIMIT - 1] = 5;
+    program[DENSE_LIMIT] = 6;
+    program[DENSE_LIMIT + 1] = 7;
+    let mut memory = Memory::new(&program);
+    assert_eq!(memory.dense.len(), DENSE_LIMIT);
+    assert_eq!(memory.read(DENSE_LIMIT - 1), 5);
+    assert_eq!(memory.read(DENSE_LIMIT), 6);
+    assert_eq!(memory.read(DENSE_LIMIT + 1), 7);
+    assert_eq!(memory.len(), DENSE_LIMIT + 2);
+
+    memory.write(DENSE_LIMIT, -6);
+    assert_eq!(memory.read(DENSE_LIMIT), -6);
+}